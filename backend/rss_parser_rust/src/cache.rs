@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::types::{CacheValidators, FetchResult};
+
+/// What a [`FeedCache`] remembers about one feed URL.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CachedFeed {
+    /// Validators to send with the next request.
+    pub validators: CacheValidators,
+    /// Consecutive `304 Not Modified` responses since the feed last changed.
+    pub not_modified_count: u32,
+}
+
+/// A fetch outcome a [`ValidatorStore`] learns from, kept without the
+/// response body so it can wait until the feed has been parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidatorUpdate {
+    /// A full response and the validators it carried.
+    Fresh {
+        url: String,
+        validators: CacheValidators,
+    },
+    /// A `304 Not Modified` response.
+    NotModified {
+        url: String,
+        validators: CacheValidators,
+    },
+}
+
+impl ValidatorUpdate {
+    /// The update a fetch result carries; failed fetches carry none, so the
+    /// next run still asks conditionally.
+    pub fn from_result(result: &FetchResult) -> Option<Self> {
+        match result {
+            FetchResult::Success(raw) => Some(Self::Fresh {
+                url: raw.url.clone(),
+                validators: raw.validators.clone(),
+            }),
+            FetchResult::NotModified(unchanged) => Some(Self::NotModified {
+                url: unchanged.url.clone(),
+                validators: unchanged.validators.clone(),
+            }),
+            FetchResult::Error(_) => None,
+        }
+    }
+}
+
+/// Validators shared between a parse run and its [`FeedCache`] handle,
+/// keyed by feed URL.
+#[derive(Debug, Default)]
+pub struct ValidatorStore {
+    entries: Mutex<HashMap<String, CachedFeed>>,
+}
+
+impl ValidatorStore {
+    /// The validators to send, keyed by feed URL.
+    pub fn validators(&self) -> HashMap<String, CacheValidators> {
+        self.lock()
+            .iter()
+            .map(|(url, cached)| (url.clone(), cached.validators.clone()))
+            .collect()
+    }
+
+    /// Applies a run's updates. A fresh response replaces the feed's
+    /// validators, dropping the entry when the server sent none, and a `304`
+    /// bumps its streak. Feeds without an update keep their entry.
+    pub fn record(&self, updates: impl IntoIterator<Item = ValidatorUpdate>) {
+        let mut entries = self.lock();
+        for update in updates {
+            match update {
+                ValidatorUpdate::Fresh { url, validators } => {
+                    if validators == CacheValidators::default() {
+                        entries.remove(&url);
+                    } else {
+                        entries.insert(
+                            url,
+                            CachedFeed {
+                                validators,
+                                not_modified_count: 0,
+                            },
+                        );
+                    }
+                }
+                ValidatorUpdate::NotModified { url, validators } => {
+                    let cached = entries.entry(url).or_default();
+                    cached.validators = validators;
+                    cached.not_modified_count = cached.not_modified_count.saturating_add(1);
+                }
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, CachedFeed>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Conditional-request cache for `parse_feeds_parallel`.
+///
+/// Pass one as `cache=` on every run: its `ETag` and `Last-Modified` values
+/// are sent with each feed request, and it is updated in place with the
+/// validators each response returned, once that feed parsed in full, and a
+/// count of consecutive `304` responses per feed. `to_dict` and `FeedCache.from_dict` round-trip it
+/// through `{url: {"etag", "last_modified", "not_modified_count"}}` so it
+/// can be saved between processes.
+#[pyclass(name = "FeedCache")]
+#[derive(Clone, Default)]
+pub struct FeedCache {
+    store: Arc<ValidatorStore>,
+}
+
+impl FeedCache {
+    /// The shared store to thread into a run.
+    pub fn store(&self) -> Arc<ValidatorStore> {
+        self.store.clone()
+    }
+}
+
+#[pymethods]
+impl FeedCache {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a cache from the dict `to_dict` returns. A missing
+    /// `not_modified_count` counts as zero, so the `cache_validators` format
+    /// is accepted too.
+    #[staticmethod]
+    fn from_dict(entries: HashMap<String, Bound<'_, PyDict>>) -> PyResult<Self> {
+        let mut cached = HashMap::with_capacity(entries.len());
+        for (url, fields) in entries {
+            let text = |name: &str| -> PyResult<Option<String>> {
+                match fields.get_item(name)? {
+                    Some(value) if !value.is_none() => value.extract().map(Some),
+                    _ => Ok(None),
+                }
+            };
+            let not_modified_count = match fields.get_item("not_modified_count")? {
+                Some(value) if !value.is_none() => value.extract()?,
+                _ => 0,
+            };
+            let validators = CacheValidators {
+                etag: text("etag")?,
+                last_modified: text("last_modified")?,
+            };
+            cached.insert(
+                url,
+                CachedFeed {
+                    validators,
+                    not_modified_count,
+                },
+            );
+        }
+        let cache = Self::default();
+        *cache.store.lock() = cached;
+        Ok(cache)
+    }
+
+    /// The cached entries as `{url: {"etag", "last_modified",
+    /// "not_modified_count"}}`.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        for (url, cached) in self.store.lock().iter() {
+            let entry = PyDict::new_bound(py);
+            entry.set_item("etag", &cached.validators.etag)?;
+            entry.set_item("last_modified", &cached.validators.last_modified)?;
+            entry.set_item("not_modified_count", cached.not_modified_count)?;
+            dict.set_item(url, entry)?;
+        }
+        Ok(dict)
+    }
+
+    /// Forgets every feed.
+    fn clear(&self) {
+        self.store.lock().clear();
+    }
+
+    fn __len__(&self) -> usize {
+        self.store.lock().len()
+    }
+
+    fn __contains__(&self, url: &str) -> bool {
+        self.store.lock().contains_key(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CachedFeed, ValidatorStore, ValidatorUpdate};
+    use crate::types::{CacheValidators, FetchError, FetchResult, NotModifiedFeed, RawFeed};
+
+    fn validators(etag: &str) -> CacheValidators {
+        CacheValidators {
+            etag: Some(etag.to_string()),
+            last_modified: None,
+        }
+    }
+
+    #[test]
+    fn tracks_fresh_validators_and_not_modified_streaks() {
+        let store = ValidatorStore::default();
+        let record = |results: &[FetchResult]| {
+            store.record(results.iter().filter_map(ValidatorUpdate::from_result));
+        };
+        let fresh = |url: &str, validators: CacheValidators| {
+            FetchResult::Success(RawFeed {
+                url: url.to_string(),
+                validators,
+                ..Default::default()
+            })
+        };
+        record(&[
+            fresh("https://a.test/feed", validators("\"a1\"")),
+            fresh("https://b.test/feed", validators("\"b1\"")),
+        ]);
+
+        record(&[
+            FetchResult::NotModified(NotModifiedFeed {
+                url: "https://a.test/feed".to_string(),
+                validators: validators("\"a1\""),
+                ..Default::default()
+            }),
+            fresh("https://b.test/feed", CacheValidators::default()),
+        ]);
+        record(&[
+            FetchResult::NotModified(NotModifiedFeed {
+                url: "https://a.test/feed".to_string(),
+                validators: validators("\"a1\""),
+                ..Default::default()
+            }),
+            FetchResult::Error(FetchError {
+                source_name: "A".to_string(),
+                url: "https://a.test/feed".to_string(),
+                message: "Request timed out".to_string(),
+                error_kind: "timeout".to_string(),
+                duration_ms: 0,
+                timed_out: true,
+                body_bytes: None,
+                http_version: None,
+                status_code: None,
+                final_url: None,
+                retry_after_secs: None,
+            }),
+        ]);
+
+        let entries = store.lock().clone();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries["https://a.test/feed"],
+            CachedFeed {
+                validators: validators("\"a1\""),
+                not_modified_count: 2,
+            }
+        );
+        assert_eq!(
+            store.validators()["https://a.test/feed"].etag.as_deref(),
+            Some("\"a1\"")
+        );
+    }
+}
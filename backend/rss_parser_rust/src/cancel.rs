@@ -0,0 +1,41 @@
+use pyo3::prelude::*;
+use tokio_util::sync::CancellationToken;
+
+/// Stop signal for an in-flight `parse_feeds_parallel` run.
+///
+/// Pass one as `cancel_token=` and call `cancel()` from another thread to
+/// abort the run: queued requests are skipped, in-flight requests are
+/// dropped, and the feeds fetched so far are parsed and returned with
+/// `metrics["cancelled"]` set. A token stays cancelled, so create a new one
+/// per run.
+#[pyclass(name = "CancelToken")]
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    token: CancellationToken,
+}
+
+impl CancelToken {
+    /// The token to thread into a run.
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+}
+
+#[pymethods]
+impl CancelToken {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of every run using this token.
+    fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Whether `cancel` has been called.
+    #[getter]
+    fn cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
@@ -1,5 +1,6 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
+use scraper::{ElementRef, Html};
 
 static HTML_TAG_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"<[^>]+>").expect("valid html tag regex"));
@@ -7,29 +8,602 @@ static WHITESPACE_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"\s+").expect("valid whitespace regex"));
 static NBSP_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"[\u{00A0}\u{2009}\u{202F}]").expect("valid nbsp regex"));
+/// Whitespace runs to collapse when ideographic spacing is kept: everything
+/// `\s` matches except [`IDEOGRAPHIC_SPACES`].
+static COLLAPSIBLE_WHITESPACE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[\s&&[^\u{3000}\u{2009}\u{202F}]]+").expect("valid collapsible whitespace regex")
+});
+
+/// Spaces CJK text uses for layout, such as the full-width indent that
+/// opens a Japanese paragraph. Kept as written when ideographic spacing is
+/// preserved: the ideographic space, thin space, and narrow no-break space.
+const IDEOGRAPHIC_SPACES: &[char] = &['\u{3000}', '\u{2009}', '\u{202F}'];
+static NUMERIC_ENTITY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"&#(?:[xX]([0-9a-fA-F]{1,6})|([0-9]{1,7}));?").expect("valid numeric entity regex")
+});
+static BLOCK_BOUNDARY_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)</p\s*>|<br\s*/?>|</?div\b[^>]*>").expect("valid block boundary regex")
+});
 
 /// Strips HTML tags, decodes HTML entities, replaces non-breaking space
 /// characters with regular spaces, and collapses all whitespace into single
 /// spaces.
 pub fn clean_html(input: &str) -> String {
+    clean_html_with_spacing(input, false)
+}
+
+/// Like [`clean_html`], but with `keep_ideographic_spaces` the ideographic,
+/// thin, and narrow no-break spaces CJK text relies on are left as written,
+/// even at the ends, while other whitespace is still collapsed and trimmed.
+pub fn clean_html_with_spacing(input: &str, keep_ideographic_spaces: bool) -> String {
     if input.is_empty() {
         return String::new();
     }
 
+    let decoded = decode_entities(input);
+    flatten_text(&decoded, keep_ideographic_spaces)
+}
+
+/// Decodes named and numeric character references. A second pass catches
+/// numeric references the library leaves behind, such as double-encoded
+/// `&amp;#8217;` or ones missing their closing semicolon; references to
+/// invalid code points are left as written.
+fn decode_entities(input: &str) -> String {
     let decoded = html_escape::decode_html_entities(input);
-    let without_tags = HTML_TAG_RE.replace_all(&decoded, " ");
-    let without_nbsp = NBSP_RE.replace_all(&without_tags, " ");
-    let compact = WHITESPACE_RE.replace_all(&without_nbsp, " ");
-    compact.trim().to_string()
+    NUMERIC_ENTITY_RE
+        .replace_all(&decoded, |captures: &regex::Captures<'_>| {
+            let code = match (captures.get(1), captures.get(2)) {
+                (Some(hex), _) => u32::from_str_radix(hex.as_str(), 16).ok(),
+                (None, Some(decimal)) => decimal.as_str().parse().ok(),
+                (None, None) => None,
+            };
+            code.filter(|&code| code != 0)
+                .and_then(char::from_u32)
+                .map_or_else(|| captures[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
+fn flatten_text(decoded: &str, keep_ideographic_spaces: bool) -> String {
+    let without_tags = HTML_TAG_RE.replace_all(decoded, " ");
+    if keep_ideographic_spaces {
+        collapse_whitespace(&without_tags.replace('\u{00A0}', " "), true)
+    } else {
+        collapse_whitespace(&NBSP_RE.replace_all(&without_tags, " "), false)
+    }
+}
+
+/// Collapses whitespace runs to one space and trims the ends, sparing
+/// [`IDEOGRAPHIC_SPACES`] when `keep_ideographic_spaces` is set. Both
+/// collapsing and trimming follow Unicode's definition of whitespace.
+fn collapse_whitespace(text: &str, keep_ideographic_spaces: bool) -> String {
+    if keep_ideographic_spaces {
+        COLLAPSIBLE_WHITESPACE_RE
+            .replace_all(text, " ")
+            .trim_matches(|c: char| c.is_whitespace() && !IDEOGRAPHIC_SPACES.contains(&c))
+            .to_string()
+    } else {
+        WHITESPACE_RE.replace_all(text, " ").trim().to_string()
+    }
+}
+
+/// Like [`clean_html`], but keeps paragraph structure: `</p>`, `<br>`, and
+/// `<div>` boundaries become blank lines, and whitespace is only collapsed
+/// within each paragraph. `keep_ideographic_spaces` behaves as in
+/// [`clean_html_with_spacing`].
+pub fn clean_html_preserve_paragraphs(input: &str, keep_ideographic_spaces: bool) -> String {
+    if input.is_empty() {
+        return String::new();
+    }
+
+    let decoded = decode_entities(input);
+    BLOCK_BOUNDARY_RE
+        .split(&decoded)
+        .map(|paragraph| flatten_text(paragraph, keep_ideographic_spaces))
+        .filter(|paragraph| !paragraph.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Elements whose content is code, styling, or fallback markup rather than
+/// readable text; they are dropped along with everything inside them.
+pub const NON_CONTENT_TAGS: &[&str] = &["script", "style", "noscript", "template"];
+
+/// Elements whose content becomes its own paragraph in markdown output.
+const MARKDOWN_BLOCK_TAGS: &[&str] = &[
+    "p",
+    "div",
+    "ul",
+    "ol",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "blockquote",
+    "section",
+    "article",
+    "table",
+    "tr",
+];
+
+/// Wraps the text of `inner` in `open`/`close`, keeping its surrounding
+/// whitespace outside the markers.
+fn wrap_inline(inner: &str, open: &str, close: &str) -> String {
+    let trimmed = inner.trim();
+    if trimmed.is_empty() {
+        return inner.to_string();
+    }
+    let leading = &inner[..inner.len() - inner.trim_start().len()];
+    let trailing = &inner[inner.trim_end().len()..];
+    format!("{leading}{open}{trimmed}{close}{trailing}")
+}
+
+/// Backslash-escapes the characters that would close or open a markdown
+/// link inside its text.
+fn escape_link_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '[' | ']' | '(' | ')' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Percent-encodes the characters that would end a markdown link
+/// destination early, including whitespace.
+fn escape_link_url(url: &str) -> String {
+    let mut escaped = String::with_capacity(url.len());
+    for ch in url.chars() {
+        match ch {
+            '[' | ']' | '(' | ')' | '<' | '>' => escaped.push_str(&format!("%{:02X}", ch as u32)),
+            ch if ch.is_whitespace() => {
+                let mut buf = [0; 4];
+                for byte in ch.encode_utf8(&mut buf).bytes() {
+                    escaped.push_str(&format!("%{byte:02X}"));
+                }
+            }
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn markdown_from_children(element: ElementRef<'_>, out: &mut String) {
+    for child in element.children() {
+        if let Some(text) = child.value().as_text() {
+            out.push_str(&WHITESPACE_RE.replace_all(&NBSP_RE.replace_all(text, " "), " "));
+        } else if let Some(child) = ElementRef::wrap(child) {
+            markdown_from_element(child, out);
+        }
+    }
+}
+
+fn markdown_from_element(element: ElementRef<'_>, out: &mut String) {
+    let inner = || {
+        let mut inner = String::new();
+        markdown_from_children(element, &mut inner);
+        inner
+    };
+
+    match element.value().name() {
+        name if NON_CONTENT_TAGS.contains(&name) => {}
+        "br" => out.push_str("\n\n"),
+        "a" => {
+            let text = inner();
+            match element
+                .attr("href")
+                .and_then(safe_href)
+                .filter(|href| !href.is_empty())
+            {
+                Some(href) => out.push_str(&wrap_inline(
+                    &escape_link_text(&text),
+                    "[",
+                    &format!("]({})", escape_link_url(href)),
+                )),
+                None => out.push_str(&text),
+            }
+        }
+        "strong" | "b" => out.push_str(&wrap_inline(&inner(), "**", "**")),
+        "em" | "i" => out.push_str(&wrap_inline(&inner(), "_", "_")),
+        "li" => {
+            out.push_str("\n- ");
+            out.push_str(inner().trim());
+        }
+        name if MARKDOWN_BLOCK_TAGS.contains(&name) => {
+            out.push_str("\n\n");
+            out.push_str(&inner());
+            out.push_str("\n\n");
+        }
+        _ => out.push_str(&inner()),
+    }
+}
+
+/// Converts an HTML fragment to lightweight markdown: links become
+/// `[text](url)`, `<strong>`/`<b>` become `**`, `<em>`/`<i>` become `_`,
+/// list items become `- ` lines, and paragraphs are separated by blank
+/// lines. Other tags are stripped. Entities are decoded exactly once, so
+/// link URLs come through as written. Links pass the same scheme check as
+/// [`sanitize_html`], so `javascript:` links keep only their text, and
+/// brackets in link text and URLs are escaped.
+pub fn clean_html_to_markdown(input: &str) -> String {
+    if input.is_empty() {
+        return String::new();
+    }
+
+    let fragment = Html::parse_fragment(input);
+    let mut raw = String::new();
+    markdown_from_children(fragment.root_element(), &mut raw);
+
+    let mut output = String::with_capacity(raw.len());
+    let mut blank_run = 0;
+    for line in raw.lines().map(str::trim) {
+        if line.is_empty() {
+            blank_run += 1;
+            continue;
+        }
+        if !output.is_empty() {
+            output.push_str(if blank_run > 0 { "\n\n" } else { "\n" });
+        }
+        output.push_str(line);
+        blank_run = 0;
+    }
+    output
+}
+
+/// Elements [`sanitize_html`] keeps, without attributes except `href` on
+/// links.
+const SAFE_HTML_TAGS: &[&str] = &[
+    "p",
+    "a",
+    "strong",
+    "em",
+    "ul",
+    "ol",
+    "li",
+    "blockquote",
+    "br",
+];
+
+/// Embedded content [`sanitize_html`] drops entirely, on top of
+/// [`NON_CONTENT_TAGS`].
+const UNSAFE_EMBED_TAGS: &[&str] = &["iframe", "object", "embed", "frame", "frameset"];
+
+/// URL schemes a sanitized link may use. Scheme-less (relative) links are
+/// kept as well.
+const SAFE_HREF_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// Returns `href` if it is relative or uses a [`SAFE_HREF_SCHEMES`] scheme.
+/// Browsers ignore whitespace and control characters inside a scheme, so
+/// `java\tscript:` is judged as `javascript:`.
+fn safe_href(href: &str) -> Option<&str> {
+    let href = href.trim();
+    let compact: String = href
+        .chars()
+        .filter(|ch| !ch.is_whitespace() && !ch.is_control())
+        .take_while(|ch| !matches!(ch, '/' | '?' | '#'))
+        .collect();
+    match compact.split_once(':') {
+        Some((scheme, _)) => SAFE_HREF_SCHEMES
+            .iter()
+            .any(|safe| scheme.eq_ignore_ascii_case(safe))
+            .then_some(href),
+        None => Some(href),
+    }
+}
+
+fn sanitize_children(element: ElementRef<'_>, out: &mut String) {
+    for child in element.children() {
+        if let Some(text) = child.value().as_text() {
+            out.push_str(&html_escape::encode_text(&NBSP_RE.replace_all(text, " ")));
+        } else if let Some(child) = ElementRef::wrap(child) {
+            sanitize_element(child, out);
+        }
+    }
+}
+
+fn sanitize_element(element: ElementRef<'_>, out: &mut String) {
+    match element.value().name() {
+        name if NON_CONTENT_TAGS.contains(&name) || UNSAFE_EMBED_TAGS.contains(&name) => {}
+        "br" => out.push_str("<br>"),
+        "a" => match element.attr("href").and_then(safe_href) {
+            Some(href) if !href.is_empty() => {
+                out.push_str("<a href=\"");
+                out.push_str(&html_escape::encode_double_quoted_attribute(href));
+                out.push_str("\">");
+                sanitize_children(element, out);
+                out.push_str("</a>");
+            }
+            _ => sanitize_children(element, out),
+        },
+        name if SAFE_HTML_TAGS.contains(&name) => {
+            out.push_str(&format!("<{name}>"));
+            sanitize_children(element, out);
+            out.push_str(&format!("</{name}>"));
+        }
+        _ => sanitize_children(element, out),
+    }
+}
+
+/// Reduces an HTML fragment to a safe subset for inline rendering: `p`,
+/// `a`, `strong`, `em`, `ul`, `ol`, `li`, `blockquote`, and `br` are kept,
+/// other tags are unwrapped to their text, and scripts, styles, and embeds
+/// are removed with their content. Every attribute is dropped except `href`
+/// on links, and only for relative, `http(s)`, or `mailto` URLs, so event
+/// handlers and `javascript:` links cannot survive. Text is re-escaped.
+pub fn sanitize_html(input: &str) -> String {
+    if input.is_empty() {
+        return String::new();
+    }
+
+    let fragment = Html::parse_fragment(input);
+    let mut output = String::new();
+    sanitize_children(fragment.root_element(), &mut output);
+    WHITESPACE_RE.replace_all(&output, " ").trim().to_string()
+}
+
+fn is_zero_width(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'
+    )
+}
+
+fn is_invisible_format(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{00AD}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+fn is_emoji(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{1F000}'..='\u{1FAFF}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2B00}'..='\u{2BFF}'
+            | '\u{FE0E}'..='\u{FE0F}'
+            | '\u{E0020}'..='\u{E007F}'
+            | '\u{20E3}'
+    )
+}
+
+/// Removes control characters, zero-width characters, and bidi/format marks
+/// from already-cleaned text, optionally removing emoji as well.
+///
+/// When emoji are kept, zero-width joiners between two emoji are preserved so
+/// sequences such as family or profession glyphs still render as one symbol.
+/// Ordinary punctuation is left untouched, and `keep_ideographic_spaces`
+/// behaves as in [`clean_html_with_spacing`].
+pub fn strip_decorative_chars(
+    input: &str,
+    strip_emoji: bool,
+    keep_ideographic_spaces: bool,
+) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::with_capacity(input.len());
+
+    for (index, &ch) in chars.iter().enumerate() {
+        if ch == '\u{200D}' && !strip_emoji {
+            let joins_emoji = index > 0
+                && chars.get(index + 1).is_some_and(|next| is_emoji(*next))
+                && is_emoji(chars[index - 1]);
+            if joins_emoji {
+                output.push(ch);
+            }
+            continue;
+        }
+        if is_zero_width(ch) || is_invisible_format(ch) {
+            continue;
+        }
+        if ch.is_control() {
+            if ch.is_whitespace() {
+                output.push(' ');
+            }
+            continue;
+        }
+        if strip_emoji && is_emoji(ch) {
+            continue;
+        }
+        output.push(ch);
+    }
+
+    collapse_whitespace(&output, keep_ideographic_spaces)
+}
+
+/// Shortens text to at most `max_chars` characters, ellipsis included,
+/// cutting at the last word boundary that fits and appending `…` when
+/// anything was dropped. A single word longer than the limit is cut
+/// mid-word. Counts characters, not bytes, so multi-byte text is never
+/// split inside a character.
+pub fn truncate_at_word_boundary(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let Some(budget) = max_chars.checked_sub(1) else {
+        return String::new();
+    };
+    let cut = text
+        .char_indices()
+        .nth(budget)
+        .map_or(text.len(), |(index, _)| index);
+    let head = &text[..cut];
+    let at_boundary = text[cut..].starts_with(char::is_whitespace);
+    let kept = if at_boundary {
+        head
+    } else {
+        head.rfind(char::is_whitespace)
+            .map_or(head, |space| &head[..space])
+    };
+    format!("{}\u{2026}", kept.trim_end())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::clean_html;
+    use super::{
+        clean_html, clean_html_preserve_paragraphs, clean_html_to_markdown,
+        clean_html_with_spacing, sanitize_html, strip_decorative_chars, truncate_at_word_boundary,
+    };
 
     #[test]
     fn cleans_html_entities() {
         let output = clean_html("<p>Hello&nbsp;<strong>World</strong></p>");
         assert_eq!(output, "Hello World");
     }
+
+    #[test]
+    fn decodes_numeric_character_references() {
+        assert_eq!(
+            clean_html("It&#8217;s a &#x201C;test&#x201D;"),
+            "It\u{2019}s a \u{201C}test\u{201D}"
+        );
+        assert_eq!(
+            clean_html("It&amp;#8217;s &#8220;quoted&#8221 &#xD800; ok"),
+            "It\u{2019}s \u{201C}quoted\u{201D} &#xD800; ok"
+        );
+    }
+
+    #[test]
+    fn preserves_paragraph_breaks() {
+        assert_eq!(
+            clean_html_preserve_paragraphs("<p>One</p><p>Two</p>", false),
+            "One\n\nTwo"
+        );
+        assert_eq!(
+            clean_html_preserve_paragraphs("<div>Line  one<br/>Line\n two</div>", false),
+            "Line one\n\nLine two"
+        );
+    }
+
+    #[test]
+    fn truncates_summaries_at_word_boundaries() {
+        let text = "The council approved the budget after a long debate";
+
+        assert_eq!(truncate_at_word_boundary(text, 100), text);
+        assert_eq!(
+            truncate_at_word_boundary(text, 24),
+            "The council approved\u{2026}"
+        );
+        assert_eq!(
+            truncate_at_word_boundary(text, 21),
+            "The council approved\u{2026}"
+        );
+        assert_eq!(
+            truncate_at_word_boundary("Supercalifragilistic", 6),
+            "Super\u{2026}"
+        );
+        assert_eq!(truncate_at_word_boundary(text, 0), "");
+        // Multi-byte characters count once and are never split.
+        assert_eq!(
+            truncate_at_word_boundary("東京都の予算案が可決されました", 8),
+            "東京都の予算案\u{2026}"
+        );
+        assert_eq!(
+            truncate_at_word_boundary("Café crème brûlée", 12),
+            "Café crème\u{2026}"
+        );
+    }
+
+    #[test]
+    fn collapses_ideographic_spaces_by_default() {
+        assert_eq!(
+            clean_html("\u{3000}<p>東京\u{3000}\u{3000}大阪</p>\u{2009}\n"),
+            "東京 大阪"
+        );
+    }
+
+    #[test]
+    fn keeps_ideographic_spacing_when_asked() {
+        // A full-width indent opens the paragraph and separates the names.
+        let html =
+            "<p>\u{3000}今日は晴れです。\n  東京\u{3000}大阪\u{2009}名古屋&#12288;</p>\u{00A0}";
+
+        assert_eq!(
+            clean_html_with_spacing(html, true),
+            "\u{3000}今日は晴れです。 東京\u{3000}大阪\u{2009}名古屋\u{3000}"
+        );
+        assert_eq!(
+            clean_html_preserve_paragraphs("<p>\u{3000}一段落目</p><p>\u{3000}二段落目</p>", true),
+            "\u{3000}一段落目\n\n\u{3000}二段落目"
+        );
+        assert_eq!(
+            strip_decorative_chars("\u{3000}速報\u{200B}：\u{3000}地震", false, true),
+            "\u{3000}速報：\u{3000}地震"
+        );
+    }
+
+    #[test]
+    fn converts_html_to_markdown() {
+        let html = r#"<p>Read <a href="https://example.com/a?x=1&amp;y=2">the <b>full</b> story</a>
+            now &amp; <em>later</em>.</p><ul><li>One</li><li><strong>Two</strong></li></ul>
+            <script>ignored()</script><p>Last<br>line</p>"#;
+
+        assert_eq!(
+            clean_html_to_markdown(html),
+            "Read [the **full** story](https://example.com/a?x=1&y=2) now & _later_.\n\n\
+             - One\n- **Two**\n\nLast\n\nline"
+        );
+    }
+
+    #[test]
+    fn escapes_markdown_links_and_drops_unsafe_schemes() {
+        let html = r#"<a href="javascript:alert(1)">bad</a>
+            <a href="https://example.com/a_(b) c">see [1] (<i>x</i>)</a>
+            <a href="https://example.com/x)[evil](javascript:alert(1)">y</a>"#;
+
+        assert_eq!(
+            clean_html_to_markdown(html),
+            "bad [see \\[1\\] \\(_x_\\)](https://example.com/a_%28b%29%20c) \
+             [y](https://example.com/x%29%5Bevil%5D%28javascript:alert%281%29)"
+        );
+    }
+
+    #[test]
+    fn sanitizes_html_to_a_safe_subset() {
+        let html = r#"<p onclick="steal()">Hi <script>alert(1)</script><b>there</b>,
+            <a href="https://example.com/a?x=1&amp;y=2" target="_blank">read</a>
+            <a href=" JaVa&#9;Script:alert(1)">bad</a></p><style>p{color:red}</style>
+            <blockquote><em>1 &lt; 2</em><img src=x onerror=alert(1)></blockquote>
+            <iframe src="https://evil.example"></iframe><ul><li>One<br/>Two</li></ul>"#;
+
+        let output = sanitize_html(html);
+
+        assert_eq!(
+            output,
+            "<p>Hi there, <a href=\"https://example.com/a?x=1&amp;y=2\">read</a> bad</p> \
+             <blockquote><em>1 &lt; 2</em></blockquote> <ul><li>One<br>Two</li></ul>"
+        );
+        assert!(!output.contains("alert"));
+    }
+
+    #[test]
+    fn strips_zero_width_and_control_characters() {
+        let output = strip_decorative_chars(
+            "Break\u{200B}ing\u{0007} news\u{FEFF}: markets",
+            false,
+            false,
+        );
+        assert_eq!(output, "Breaking news: markets");
+    }
+
+    #[test]
+    fn keeps_zero_width_joiner_sequences_when_emoji_are_kept() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let output = strip_decorative_chars(&format!("{family} Family\u{200D} news"), false, false);
+        assert_eq!(output, format!("{family} Family news"));
+    }
+
+    #[test]
+    fn removes_zero_width_joiner_sequences_with_emoji() {
+        let output = strip_decorative_chars(
+            "\u{1F525} Breaking \u{1F468}\u{200D}\u{1F4BB}\u{FE0F} Tech, news!",
+            true,
+            false,
+        );
+        assert_eq!(output, "Breaking Tech, news!");
+    }
 }
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use pyo3::prelude::*;
+use reqwest::{Client, Proxy};
+
+use crate::fetcher::{build_client, ClientConfig};
+
+/// Reusable HTTP client for `parse_feeds_parallel` and
+/// `fetch_feeds_parallel`.
+///
+/// Create one in Python and pass it as `client=` on every call so the
+/// connection pool and keep-alive connections survive between runs. The
+/// default User-Agent and request timeout are fixed at construction; a
+/// call's own `timeout_ms` or `user_agents` still take precedence.
+///
+/// `proxy_url` routes every request through an `http://`, `https://`, or
+/// `socks5://` proxy (`socks5h://` resolves hostnames on the proxy). A
+/// malformed proxy URL raises `RuntimeError` here rather than on first use.
+///
+/// `connect_timeout_secs` bounds connecting (including the TLS handshake) and
+/// `read_timeout_secs` bounds each wait for more response data, so a stalled
+/// server fails fast while a slow but steady download keeps going. Both sit
+/// inside the overall request timeout, which still applies; raise it to
+/// allow long downloads. Unset, only the overall timeout applies.
+///
+/// `danger_accept_invalid_certs=True` turns off TLS certificate verification
+/// for every request made with this client. This is insecure: anyone on the
+/// network path can impersonate the server and read or alter the feeds. Use
+/// it only for self-hosted servers with self-signed certificates, on a
+/// separate client from the one that fetches public feeds.
+#[pyclass(name = "FeedClient")]
+#[derive(Clone)]
+pub struct FeedClient {
+    client: Client,
+    timeout: Option<Duration>,
+}
+
+impl FeedClient {
+    /// The pooled client to fetch with.
+    pub fn client(&self) -> Client {
+        self.client.clone()
+    }
+
+    /// Request timeout chosen at construction, if any.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+/// Proxy URL schemes `reqwest` can route through.
+const PROXY_SCHEMES: &[&str] = &["http", "https", "socks5", "socks5h"];
+
+fn proxy_from_url(proxy_url: &str) -> PyResult<Proxy> {
+    let invalid = |reason: String| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+            "Invalid proxy_url {proxy_url:?}: {reason}"
+        ))
+    };
+    let parsed = url::Url::parse(proxy_url.trim()).map_err(|err| invalid(err.to_string()))?;
+    if !PROXY_SCHEMES.contains(&parsed.scheme()) {
+        return Err(invalid(format!(
+            "unsupported scheme {:?}, expected one of {}",
+            parsed.scheme(),
+            PROXY_SCHEMES.join(", ")
+        )));
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("missing host".to_string()));
+    }
+    Proxy::all(parsed.as_str()).map_err(|err| invalid(err.to_string()))
+}
+
+/// Converts an optional timeout in seconds, rejecting zero, negative, and
+/// non-finite values with `ValueError`.
+fn timeout_from_secs(name: &str, secs: Option<f64>) -> PyResult<Option<Duration>> {
+    secs.map(|secs| {
+        Duration::try_from_secs_f64(secs)
+            .ok()
+            .filter(|timeout| !timeout.is_zero())
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "{name} must be a positive number of seconds, got {secs}"
+                ))
+            })
+    })
+    .transpose()
+}
+
+#[pymethods]
+impl FeedClient {
+    #[new]
+    #[pyo3(signature = (
+        user_agent=None,
+        timeout_ms=None,
+        proxy_url=None,
+        connect_timeout_secs=None,
+        read_timeout_secs=None,
+        danger_accept_invalid_certs=false,
+    ))]
+    fn new(
+        user_agent: Option<String>,
+        timeout_ms: Option<u64>,
+        proxy_url: Option<String>,
+        connect_timeout_secs: Option<f64>,
+        read_timeout_secs: Option<f64>,
+        danger_accept_invalid_certs: bool,
+    ) -> PyResult<Self> {
+        let config = ClientConfig {
+            user_agent,
+            proxy: proxy_url.as_deref().map(proxy_from_url).transpose()?,
+            connect_timeout: timeout_from_secs("connect_timeout_secs", connect_timeout_secs)?,
+            read_timeout: timeout_from_secs("read_timeout_secs", read_timeout_secs)?,
+            danger_accept_invalid_certs,
+        };
+        let client = build_client(config).map_err(|err| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Failed to build HTTP client: {err}"
+            ))
+        })?;
+        Ok(Self {
+            client,
+            timeout: timeout_ms.map(|ms| Duration::from_millis(ms.max(1))),
+        })
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Completed requests whose latencies feed the p95 estimate.
+const LATENCY_WINDOW: usize = 20;
+
+/// Completions needed before latency alone can shrink the limit.
+const MIN_LATENCY_SAMPLES: usize = 5;
+
+/// Additive-increase/multiplicative-decrease controller for the fetch
+/// semaphore.
+///
+/// The limit starts at half of `max` and is judged once per round, a round
+/// being as many completions as the limit: a round in which nothing went
+/// wrong adds one permit. A failure that suggests overload, or a p95 latency
+/// above the target, halves the limit at once, after which the requests
+/// already in flight drain before another cut. Shrinking cannot revoke
+/// permits already handed out, so the surplus is owed and forgotten as
+/// requests finish.
+#[derive(Debug)]
+pub struct AdaptiveConcurrency {
+    semaphore: Arc<Semaphore>,
+    max: usize,
+    latency_target: Duration,
+    state: Mutex<ControllerState>,
+}
+
+#[derive(Debug)]
+struct ControllerState {
+    limit: usize,
+    owed: usize,
+    completed: usize,
+    round_length: usize,
+    troubled: bool,
+    cooling: bool,
+    recent: VecDeque<Duration>,
+}
+
+impl AdaptiveConcurrency {
+    /// A controller allowing up to `max` requests at once that treats a p95
+    /// latency above `latency_target` as congestion.
+    pub fn new(max: usize, latency_target: Duration) -> Self {
+        let max = max.max(1);
+        let limit = max.div_ceil(2);
+        Self {
+            semaphore: Arc::new(Semaphore::new(limit)),
+            max,
+            latency_target,
+            state: Mutex::new(ControllerState {
+                limit,
+                owed: 0,
+                completed: 0,
+                round_length: limit,
+                troubled: false,
+                cooling: false,
+                recent: VecDeque::with_capacity(LATENCY_WINDOW),
+            }),
+        }
+    }
+
+    /// The semaphore whose permits this controller sizes.
+    pub fn semaphore(&self) -> Arc<Semaphore> {
+        self.semaphore.clone()
+    }
+
+    /// The current concurrency limit.
+    pub fn limit(&self) -> usize {
+        self.lock().limit
+    }
+
+    /// Records a finished request and returns its permit. `failed` marks a
+    /// failure that suggests overload, such as a timeout or a 5xx response.
+    pub fn finish(&self, permit: OwnedSemaphorePermit, elapsed: Duration, failed: bool) {
+        let mut state = self.lock();
+        if state.recent.len() == LATENCY_WINDOW {
+            state.recent.pop_front();
+        }
+        state.recent.push_back(elapsed);
+        state.completed += 1;
+
+        let slow =
+            state.recent.len() >= MIN_LATENCY_SAMPLES && p95(&state.recent) > self.latency_target;
+        if failed || slow {
+            state.troubled = true;
+            if !state.cooling && state.limit > 1 {
+                let reduced = state.limit / 2;
+                state.owed += state.limit - reduced;
+                // Requests started under the old limit finish before the
+                // next round, and only post-cut latencies count.
+                state.round_length = state.limit;
+                state.limit = reduced;
+                state.completed = 0;
+                state.cooling = true;
+                state.recent.clear();
+            }
+        }
+        if state.completed >= state.round_length {
+            if !state.troubled && state.limit < self.max {
+                state.limit += 1;
+                if state.owed > 0 {
+                    state.owed -= 1;
+                } else {
+                    self.semaphore.add_permits(1);
+                }
+            }
+            state.completed = 0;
+            state.round_length = state.limit;
+            state.troubled = false;
+            state.cooling = false;
+        }
+
+        if state.owed > 0 {
+            state.owed -= 1;
+            permit.forget();
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ControllerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// 95th-percentile latency of a non-empty window, by nearest rank.
+fn p95(latencies: &VecDeque<Duration>) -> Duration {
+    let mut sorted: Vec<Duration> = latencies.iter().copied().collect();
+    sorted.sort_unstable();
+    let rank = (sorted.len() * 95).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::AdaptiveConcurrency;
+
+    const FAST: Duration = Duration::from_millis(50);
+
+    /// Runs as many requests as there are free permits, all at once.
+    fn run_batch(controller: &AdaptiveConcurrency, elapsed: Duration, failed: bool) {
+        let semaphore = controller.semaphore();
+        let permits: Vec<_> =
+            std::iter::from_fn(|| semaphore.clone().try_acquire_owned().ok()).collect();
+        for permit in permits {
+            controller.finish(permit, elapsed, failed);
+        }
+    }
+
+    #[test]
+    fn grows_by_one_per_clean_round_up_to_the_maximum() {
+        let controller = AdaptiveConcurrency::new(6, Duration::from_secs(1));
+        assert_eq!(controller.limit(), 3);
+
+        run_batch(&controller, FAST, false);
+        assert_eq!(controller.limit(), 4);
+        for _ in 0..5 {
+            run_batch(&controller, FAST, false);
+        }
+        assert_eq!(controller.limit(), 6);
+        assert_eq!(controller.semaphore().available_permits(), 6);
+    }
+
+    #[test]
+    fn halves_once_per_round_of_failures_and_reclaims_permits() {
+        let controller = AdaptiveConcurrency::new(16, Duration::from_secs(1));
+        assert_eq!(controller.limit(), 8);
+
+        // All eight in-flight requests fail, but only the first cut applies.
+        run_batch(&controller, FAST, true);
+        assert_eq!(controller.limit(), 4);
+        assert_eq!(controller.semaphore().available_permits(), 4);
+
+        run_batch(&controller, FAST, true);
+        assert_eq!(controller.limit(), 2);
+        assert_eq!(controller.semaphore().available_permits(), 2);
+    }
+
+    #[test]
+    fn slow_responses_shrink_the_limit() {
+        let controller = AdaptiveConcurrency::new(20, Duration::from_millis(500));
+        assert_eq!(controller.limit(), 10);
+
+        run_batch(&controller, Duration::from_secs(2), false);
+
+        assert_eq!(controller.limit(), 5);
+        assert_eq!(controller.semaphore().available_permits(), 5);
+    }
+}
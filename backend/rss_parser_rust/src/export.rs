@@ -0,0 +1,106 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+
+/// Columns written when the caller does not choose any.
+const DEFAULT_CSV_COLUMNS: &[&str] = &[
+    "title",
+    "link",
+    "description",
+    "published",
+    "source",
+    "authors",
+    "category",
+    "image",
+];
+
+/// Separator used when a list field such as `authors` is flattened into one
+/// cell.
+const LIST_CELL_SEPARATOR: &str = "; ";
+
+/// Writes a header row and data rows as RFC 4180 CSV, quoting any cell that
+/// contains a comma, quote, or line break.
+pub fn write_csv(columns: &[String], rows: &[Vec<String>]) -> Result<String, String> {
+    let mut writer = csv::WriterBuilder::new()
+        .terminator(csv::Terminator::CRLF)
+        .from_writer(Vec::new());
+    writer
+        .write_record(columns)
+        .map_err(|err| err.to_string())?;
+    for row in rows {
+        writer.write_record(row).map_err(|err| err.to_string())?;
+    }
+    let bytes = writer.into_inner().map_err(|err| err.to_string())?;
+    String::from_utf8(bytes).map_err(|err| err.to_string())
+}
+
+fn cell_text(value: &Bound<'_, PyAny>) -> PyResult<String> {
+    if value.is_none() {
+        return Ok(String::new());
+    }
+    if let Ok(text) = value.downcast::<PyString>() {
+        return Ok(text.to_string());
+    }
+    if value.is_instance_of::<PyList>() || value.is_instance_of::<PyTuple>() {
+        let items = value
+            .iter()?
+            .map(|item| cell_text(&item?))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(items.join(LIST_CELL_SEPARATOR));
+    }
+    Ok(value.str()?.to_string())
+}
+
+/// Serializes article dicts (as returned in `articles`) to CSV text.
+///
+/// `columns` picks and orders the article keys to export, defaulting to
+/// title, link, description, published, source, authors, category, and image.
+/// Missing keys and `None` become empty cells, and list values such as
+/// `authors` are joined with `"; "`.
+#[pyfunction(signature = (articles, columns=None))]
+pub fn articles_to_csv(
+    articles: Vec<Bound<'_, PyDict>>,
+    columns: Option<Vec<String>>,
+) -> PyResult<String> {
+    let columns = columns.unwrap_or_else(|| {
+        DEFAULT_CSV_COLUMNS
+            .iter()
+            .map(|column| column.to_string())
+            .collect()
+    });
+
+    let mut rows = Vec::with_capacity(articles.len());
+    for article in &articles {
+        let mut row = Vec::with_capacity(columns.len());
+        for column in &columns {
+            row.push(match article.get_item(column)? {
+                Some(value) => cell_text(&value)?,
+                None => String::new(),
+            });
+        }
+        rows.push(row);
+    }
+
+    write_csv(&columns, &rows).map_err(PyValueError::new_err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_csv;
+
+    #[test]
+    fn quotes_cells_with_commas_quotes_and_newlines() {
+        let columns = vec!["title".to_string(), "description".to_string()];
+        let rows = vec![vec![
+            "Plain".to_string(),
+            "Rates rise, again\nAnalysts say \"wait\"".to_string(),
+        ]];
+
+        let csv = write_csv(&columns, &rows).expect("csv output");
+
+        assert_eq!(
+            csv,
+            "title,description\r\nPlain,\"Rates rise, again\nAnalysts say \"\"wait\"\"\"\r\n"
+        );
+    }
+}
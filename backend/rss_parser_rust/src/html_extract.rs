@@ -1,8 +1,12 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use scraper::{Html, Selector};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::Value;
 
-use crate::cleaner::clean_html;
+use crate::cleaner::{clean_html, NON_CONTENT_TAGS};
+use crate::types::EventDetails;
 
 /// Result of extracting structured content from an HTML article page.
 #[derive(Debug, Default)]
@@ -16,13 +20,52 @@ pub struct ArticleExtraction {
     /// Publication date from `article:published_time` or similar `<meta>`
     /// tags.
     pub publish_date: Option<String>,
-    /// URL of the lead image from `og:image` or `twitter:image`.
+    /// URL of the lead image from `og:image` or `twitter:image`, else the
+    /// first image inside `<article>`.
     pub top_image: Option<String>,
-    /// All `<img>` source URLs found in the document.
+    /// All `<img>` source URLs found in the document, including the largest
+    /// `srcset` candidate of each image.
     pub images: Vec<String>,
     /// Meta description from `description`, `og:description`, or
     /// `twitter:description`.
     pub meta_description: Option<String>,
+    /// Event details from the first JSON-LD `Event` block, if any.
+    pub event: Option<EventDetails>,
+    /// Absolute URL of the AMP version from `<link rel="amphtml">`.
+    pub amp_url: Option<String>,
+    /// The page's canonical address from `<link rel="canonical">`, else
+    /// `og:url`, resolved against the base URL when relative.
+    pub canonical_url: Option<String>,
+    /// `<link>` elements pointing at alternate representations of the page,
+    /// in document order.
+    pub alternate_links: Vec<AlternateLink>,
+    /// Body text from the highest-scoring content container, without
+    /// navigation, sharing, or comment boilerplate.
+    pub readable_text: String,
+}
+
+/// A `<link>` element pointing at another representation of the page, such as
+/// an AMP version, a translation, or a feed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AlternateLink {
+    /// Link relation (`amphtml` or `alternate`).
+    pub rel: String,
+    /// Target URL, resolved against the page base URL when one is known.
+    pub href: String,
+    /// Declared MIME type of the target, if any.
+    pub link_type: Option<String>,
+}
+
+/// A feed a page advertises through an autodiscovery
+/// `<link rel="alternate">` tag.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DiscoveredFeed {
+    /// Feed URL, resolved against the page URL.
+    pub href: String,
+    /// The link's `title` attribute, if any.
+    pub title: Option<String>,
+    /// Declared feed MIME type, lowercased.
+    pub feed_type: String,
 }
 
 /// Result of extracting social-media image URLs from an HTML document.
@@ -40,11 +83,90 @@ pub struct ImageCandidate {
     /// Absolute or relative URL of the image.
     pub url: String,
     /// Source tag from which the image was extracted (e.g. `og:image`,
-    /// `twitter:image`, `link:image_src`).
+    /// `og:image:secure_url`, `twitter:image`, `link:image_src`,
+    /// `article:img`).
     pub source: String,
-    /// Priority rank (lower is better, 1 = og:image, 2 = twitter:image, 3 =
-    /// link:image_src).
+    /// Priority rank (lower is better, 1 = og:image or its secure URL, 2 =
+    /// twitter:image, 3 = link:image_src, 4 = first image in `<article>`).
     pub priority: usize,
+    /// Width in pixels inferred from the URL, when unambiguous.
+    pub width: Option<u32>,
+    /// Height in pixels inferred from the URL, when unambiguous.
+    pub height: Option<u32>,
+}
+
+static URL_DIMENSIONS_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[/_\-.=,])(?P<w>\d{2,5})x(?P<h>\d{2,5})(?:$|[/_\-.,?&])")
+        .expect("valid url dimensions regex")
+});
+static URL_WIDTH_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:[?&](?:w|width)=|[/,]w_)(?P<v>\d{2,5})(?:$|[&,/])")
+        .expect("valid url width regex")
+});
+static URL_HEIGHT_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:[?&](?:h|height)=|[/,]h_)(?P<v>\d{2,5})(?:$|[&,/])")
+        .expect("valid url height regex")
+});
+
+fn single_capture(regex: &Regex, url: &str, name: &str) -> Option<u32> {
+    let values: HashSet<u32> = regex
+        .captures_iter(url)
+        .filter_map(|captures| captures.name(name)?.as_str().parse().ok())
+        .collect();
+    if values.len() == 1 {
+        values.into_iter().next()
+    } else {
+        None
+    }
+}
+
+/// Infers image dimensions from common CDN URL conventions such as
+/// `/800x600/`, `_1200x630.jpg`, `?w=1200&h=630`, or Cloudinary-style
+/// `w_1200,h_630`.
+///
+/// A dimension is only returned when the URL carries exactly one distinct
+/// value for it; conflicting patterns yield `None`.
+pub fn infer_image_dimensions(url: &str) -> (Option<u32>, Option<u32>) {
+    let pairs: HashSet<(u32, u32)> = URL_DIMENSIONS_RE
+        .captures_iter(url)
+        .filter_map(|captures| {
+            Some((
+                captures.name("w")?.as_str().parse().ok()?,
+                captures.name("h")?.as_str().parse().ok()?,
+            ))
+        })
+        .collect();
+    let (pair_width, pair_height) = match pairs.len() {
+        1 => pairs
+            .into_iter()
+            .next()
+            .map_or((None, None), |(w, h)| (Some(w), Some(h))),
+        0 => (None, None),
+        _ => return (None, None),
+    };
+
+    let width = single_capture(&URL_WIDTH_RE, url, "v");
+    let height = single_capture(&URL_HEIGHT_RE, url, "v");
+    let agree = |pattern: Option<u32>, param: Option<u32>| match (pattern, param) {
+        (Some(a), Some(b)) if a != b => Err(()),
+        (a, b) => Ok(a.or(b)),
+    };
+
+    match (agree(pair_width, width), agree(pair_height, height)) {
+        (Ok(width), Ok(height)) => (width, height),
+        _ => (None, None),
+    }
+}
+
+fn image_candidate(url: String, source: &str, priority: usize) -> ImageCandidate {
+    let (width, height) = infer_image_dimensions(&url);
+    ImageCandidate {
+        url,
+        source: source.to_string(),
+        priority,
+        width,
+        height,
+    }
 }
 
 fn selector(selector: &str) -> Option<Selector> {
@@ -148,6 +270,90 @@ fn extract_top_image(document: &Html) -> Option<String> {
         document,
         &["meta[property='og:image']", "meta[name='twitter:image']"],
     )
+    .or_else(|| first_article_image(document))
+}
+
+/// First usable `<img>` inside an `<article>`, honouring lazy-load
+/// attributes.
+fn first_article_image(document: &Html) -> Option<String> {
+    let sel = selector("article img")?;
+    document.select(&sel).find_map(img_src).map(str::to_string)
+}
+
+/// Picks the highest-resolution URL from a `srcset` list such as
+/// `a.jpg 800w, b.jpg 1600w`, by its `w` (or `x`) descriptor. Candidates
+/// without a descriptor count as `1x`.
+fn best_srcset_candidate(srcset: &str) -> Option<&str> {
+    srcset
+        .split(',')
+        .filter_map(|candidate| {
+            let mut parts = candidate.split_ascii_whitespace();
+            let url = parts.next()?;
+            let descriptor = parts.next().unwrap_or("1x");
+            let value = descriptor
+                .strip_suffix(['w', 'x'])
+                .and_then(|value| value.parse::<f64>().ok())
+                .unwrap_or(0.0);
+            Some((url, value))
+        })
+        .fold(None, |best: Option<(&str, f64)>, (url, value)| match best {
+            Some((_, best_value)) if best_value >= value => best,
+            _ => Some((url, value)),
+        })
+        .map(|(url, _)| url)
+}
+
+/// Attributes lazy-loading scripts read the real image URL from.
+const LAZY_SRC_ATTRS: &[&str] = &["data-src", "data-original", "data-lazy-src"];
+
+/// File names (without extension) of stand-in images shown until a lazy
+/// loader swaps in the real one.
+const PLACEHOLDER_IMAGE_NAMES: &[&str] = &[
+    "spacer",
+    "blank",
+    "pixel",
+    "transparent",
+    "placeholder",
+    "1x1",
+];
+
+fn is_placeholder_src(src: &str) -> bool {
+    if src.starts_with("data:") {
+        return true;
+    }
+    let path = src.split(['?', '#']).next().unwrap_or_default();
+    let name = path.rsplit('/').next().unwrap_or_default().to_lowercase();
+    let stem = name.split('.').next().unwrap_or_default();
+    PLACEHOLDER_IMAGE_NAMES.contains(&stem)
+}
+
+/// The real URL of one `<img>`: a lazy-load attribute when present, else
+/// `src` unless it is a data URI or spacer placeholder.
+fn img_src(img: ElementRef<'_>) -> Option<&str> {
+    let element = img.value();
+    LAZY_SRC_ATTRS
+        .iter()
+        .filter_map(|attr| element.attr(attr))
+        .chain(element.attr("src"))
+        .map(str::trim)
+        .find(|src| !src.is_empty() && !is_placeholder_src(src))
+}
+
+/// Image URLs declared by one `<img>`: its real source (see [`img_src`]),
+/// then the largest `srcset` (or lazy `data-srcset`) candidate, which is
+/// often the only source on responsive templates.
+fn img_sources(img: ElementRef<'_>) -> Vec<String> {
+    let element = img.value();
+    let srcset = element
+        .attr("data-srcset")
+        .or_else(|| element.attr("srcset"))
+        .and_then(best_srcset_candidate);
+    img_src(img)
+        .into_iter()
+        .chain(srcset)
+        .filter(|url| !url.is_empty() && !is_placeholder_src(url))
+        .map(str::to_string)
+        .collect()
 }
 
 fn extract_images(document: &Html) -> Vec<String> {
@@ -155,19 +361,39 @@ fn extract_images(document: &Html) -> Vec<String> {
         return Vec::new();
     };
     let mut seen = HashSet::new();
-    let mut images = Vec::new();
-    for img in document.select(&sel) {
-        if let Some(src) = img.value().attr("src") {
-            let cleaned = src.trim();
-            if cleaned.is_empty() {
-                continue;
-            }
-            if seen.insert(cleaned.to_string()) {
-                images.push(cleaned.to_string());
+    document
+        .select(&sel)
+        .flat_map(img_sources)
+        .filter(|url| seen.insert(url.clone()))
+        .collect()
+}
+
+/// Text nodes under `element` in document order, leaving out everything
+/// inside `script`, `style`, `noscript`, and `template` elements, which
+/// malformed pages sometimes nest inside paragraphs.
+fn visible_text(element: ElementRef<'_>) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    collect_visible_text(element, &mut chunks);
+    chunks
+}
+
+fn collect_visible_text<'a>(element: ElementRef<'a>, out: &mut Vec<&'a str>) {
+    for child in element.children() {
+        if let Some(text) = child.value().as_text() {
+            out.push(text);
+        } else if let Some(child) = ElementRef::wrap(child) {
+            if !NON_CONTENT_TAGS.contains(&child.value().name()) {
+                collect_visible_text(child, out);
             }
         }
     }
-    images
+}
+
+fn inside_non_content(element: ElementRef<'_>) -> bool {
+    element
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .any(|ancestor| NON_CONTENT_TAGS.contains(&ancestor.value().name()))
 }
 
 fn extract_text_from_selectors(document: &Html, selectors: &[&str]) -> String {
@@ -177,7 +403,10 @@ fn extract_text_from_selectors(document: &Html, selectors: &[&str]) -> String {
         };
         let mut chunks = Vec::new();
         for el in document.select(&sel) {
-            let text = el.text().collect::<Vec<_>>().join(" ");
+            if inside_non_content(el) {
+                continue;
+            }
+            let text = visible_text(el).join(" ");
             let cleaned = clean_html(&text);
             if !cleaned.is_empty() {
                 chunks.push(cleaned);
@@ -190,12 +419,427 @@ fn extract_text_from_selectors(document: &Html, selectors: &[&str]) -> String {
     String::new()
 }
 
+/// Tags that never hold article prose, so nothing inside them is scored.
+const BOILERPLATE_TAGS: &[&str] = &[
+    "nav", "header", "footer", "aside", "form", "script", "style", "noscript", "template",
+    "button", "figure",
+];
+
+static UNLIKELY_CLASS_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)comment|share|social|footer|masthead|nav|menu|related|sidebar|promo|subscribe|newsletter|breadcrumb|cookie|banner|popup|sponsor|\bads?\b",
+    )
+    .expect("valid unlikely class regex")
+});
+static LIKELY_CLASS_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)article|body|content|entry|main|post|story|text")
+        .expect("valid likely class regex")
+});
+
+/// Paragraphs shorter than this many characters do not vote for a container.
+const MIN_PARAGRAPH_CHARS: usize = 25;
+
+fn class_and_id(element: &scraper::node::Element) -> String {
+    format!(
+        "{} {}",
+        element.attr("class").unwrap_or_default(),
+        element.attr("id").unwrap_or_default()
+    )
+}
+
+/// Whether the element is navigation, sharing widgets, comments, or similar
+/// page chrome, judged by its tag or its class and id names.
+fn is_boilerplate(element: ElementRef<'_>) -> bool {
+    let value = element.value();
+    if BOILERPLATE_TAGS.contains(&value.name()) {
+        return true;
+    }
+    let names = class_and_id(value);
+    UNLIKELY_CLASS_RE.is_match(&names) && !LIKELY_CLASS_RE.is_match(&names)
+}
+
+fn inside_boilerplate(element: ElementRef<'_>) -> bool {
+    is_boilerplate(element)
+        || element
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(is_boilerplate)
+}
+
+/// Readability's starting score for a container, from its tag and how its
+/// class and id read.
+fn container_weight(element: ElementRef<'_>) -> f64 {
+    let value = element.value();
+    let tag_weight = match value.name() {
+        "article" => 10.0,
+        "div" | "section" | "main" => 5.0,
+        "pre" | "td" | "blockquote" => 3.0,
+        "address" | "ol" | "ul" | "dl" | "dd" | "dt" | "li" | "form" => -3.0,
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "th" => -5.0,
+        _ => 0.0,
+    };
+    let names = class_and_id(value);
+    let class_weight = if LIKELY_CLASS_RE.is_match(&names) {
+        25.0
+    } else if UNLIKELY_CLASS_RE.is_match(&names) {
+        -25.0
+    } else {
+        0.0
+    };
+    tag_weight + class_weight
+}
+
+/// Share of the element's text that sits inside links.
+fn link_density(element: ElementRef<'_>, anchors: &Selector) -> f64 {
+    let total: usize = visible_text(element).into_iter().map(str::len).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let linked: usize = element
+        .select(anchors)
+        .flat_map(|anchor| anchor.text())
+        .map(str::len)
+        .sum();
+    linked as f64 / total as f64
+}
+
+/// Returns the article prose from the single best content container, with
+/// paragraphs separated by double newlines.
+///
+/// Scoring follows Mozilla Readability: every substantial paragraph outside
+/// page chrome credits its parent with a score from its length and commas,
+/// and its grandparent with half of that. Each container starts from a
+/// weight for its tag and class names, and its total is discounted by its
+/// link density. Paragraphs of the winner that are mostly links or sit in
+/// sharing or navigation widgets are dropped.
+pub fn extract_readable_text(document: &Html) -> String {
+    let (Some(paragraphs), Some(anchors)) = (selector("p, pre"), selector("a")) else {
+        return String::new();
+    };
+
+    let mut scores = HashMap::new();
+    for paragraph in document.select(&paragraphs) {
+        if inside_boilerplate(paragraph) {
+            continue;
+        }
+        let text = clean_html(&visible_text(paragraph).concat());
+        if text.chars().count() < MIN_PARAGRAPH_CHARS {
+            continue;
+        }
+        let score = 1.0 + text.matches(',').count() as f64 + (text.len() / 100).min(3) as f64;
+
+        let mut ancestors = paragraph.ancestors().filter_map(ElementRef::wrap);
+        for share in [1.0, 0.5] {
+            let Some(container) = ancestors.next() else {
+                break;
+            };
+            scores
+                .entry(container.id())
+                .or_insert_with(|| (container, container_weight(container)))
+                .1 += score * share;
+        }
+    }
+
+    let Some((best, _)) = scores
+        .into_values()
+        .map(|(container, score)| (container, score * (1.0 - link_density(container, &anchors))))
+        .max_by(|(_, left), (_, right)| left.total_cmp(right))
+    else {
+        return String::new();
+    };
+
+    best.select(&paragraphs)
+        .filter(|paragraph| {
+            !paragraph
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .take_while(|ancestor| ancestor.id() != best.id())
+                .any(is_boilerplate)
+                && !is_boilerplate(*paragraph)
+                && link_density(*paragraph, &anchors) <= 0.5
+        })
+        .map(|paragraph| clean_html(&visible_text(paragraph).join(" ")))
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn json_string(value: Option<&Value>) -> Option<String> {
+    let text = clean_html(value?.as_str()?);
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+fn is_event_type(value: Option<&Value>) -> bool {
+    let matches_event = |ty: &str| ty.ends_with("Event") || ty == "Festival";
+    match value {
+        Some(Value::String(ty)) => matches_event(ty),
+        Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).any(matches_event),
+        _ => false,
+    }
+}
+
+fn json_ld_location(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(_) => json_string(value),
+        Value::Array(items) => items.iter().find_map(|item| json_ld_location(Some(item))),
+        Value::Object(place) => {
+            json_string(place.get("name")).or_else(|| match place.get("address") {
+                Some(Value::Object(address)) => json_string(address.get("streetAddress"))
+                    .or_else(|| json_string(address.get("addressLocality"))),
+                address => json_string(address),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn find_json_ld_event(value: &Value) -> Option<EventDetails> {
+    match value {
+        Value::Array(items) => items.iter().find_map(find_json_ld_event),
+        Value::Object(object) => {
+            if is_event_type(object.get("@type")) {
+                return Some(EventDetails {
+                    name: json_string(object.get("name")),
+                    start: json_string(object.get("startDate")),
+                    end: json_string(object.get("endDate")),
+                    location: json_ld_location(object.get("location")),
+                });
+            }
+            object.get("@graph").and_then(find_json_ld_event)
+        }
+        _ => None,
+    }
+}
+
+/// Parses every `application/ld+json` block in the document, in order,
+/// skipping blocks that are not valid JSON.
+fn json_ld_blocks(document: &Html) -> Vec<Value> {
+    let Some(sel) = selector("script[type='application/ld+json']") else {
+        return Vec::new();
+    };
+    document
+        .select(&sel)
+        .filter_map(|script| {
+            let raw = script.text().collect::<String>();
+            serde_json::from_str(raw.trim()).ok()
+        })
+        .collect()
+}
+
+/// Finds the first schema.org `Event` (or subtype such as `MusicEvent`) in the
+/// document's `application/ld+json` blocks, including `@graph` containers.
+///
+/// Malformed JSON-LD blocks are skipped.
+pub fn extract_json_ld_event(document: &Html) -> Option<EventDetails> {
+    json_ld_blocks(document).iter().find_map(find_json_ld_event)
+}
+
+/// Returns the parsed contents of every `<script type="application/ld+json">`
+/// block in an HTML page, one value per block in document order.
+///
+/// Malformed JSON-LD blocks are skipped.
+pub fn extract_json_ld(html: &str) -> Vec<Value> {
+    json_ld_blocks(&Html::parse_document(html))
+}
+
+fn first_link_href(document: &Html, selector_str: &str) -> Option<String> {
+    let sel = selector(selector_str)?;
+    document
+        .select(&sel)
+        .filter_map(|el| el.value().attr("href"))
+        .map(str::trim)
+        .find(|href| !href.is_empty())
+        .map(str::to_string)
+}
+
+/// Picks the URL relative links resolve against: the caller's page URL, then
+/// `<base href>`, then the canonical or `og:url` address.
+fn resolve_base_url(document: &Html, page_url: Option<&str>) -> Option<url::Url> {
+    let page = page_url.and_then(|value| url::Url::parse(value.trim()).ok());
+    if let Some(base_href) = first_link_href(document, "base[href]") {
+        let resolved = match &page {
+            Some(page) => page.join(&base_href).ok(),
+            None => url::Url::parse(&base_href).ok(),
+        };
+        if resolved.is_some() {
+            return resolved;
+        }
+    }
+    page.or_else(|| {
+        first_link_href(document, "link[rel='canonical']")
+            .or_else(|| first_meta_content(document, &["meta[property='og:url']"]))
+            .and_then(|value| url::Url::parse(&value).ok())
+    })
+}
+
+fn resolve_href(base: Option<&url::Url>, href: &str) -> String {
+    base.and_then(|base| base.join(href).ok())
+        .map(|resolved| resolved.to_string())
+        .unwrap_or_else(|| href.to_string())
+}
+
+fn extract_alternate_links(document: &Html, base: Option<&url::Url>) -> Vec<AlternateLink> {
+    let Some(sel) = selector("link[rel][href]") else {
+        return Vec::new();
+    };
+
+    document
+        .select(&sel)
+        .filter_map(|el| {
+            let rel = el
+                .value()
+                .attr("rel")?
+                .split_ascii_whitespace()
+                .map(str::to_ascii_lowercase)
+                .find(|rel| rel == "amphtml" || rel == "alternate")?;
+            let href = el.value().attr("href")?.trim();
+            if href.is_empty() {
+                return None;
+            }
+            Some(AlternateLink {
+                rel,
+                href: resolve_href(base, href),
+                link_type: el
+                    .value()
+                    .attr("type")
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+/// MIME types that mark an alternate link as a feed.
+const FEED_LINK_TYPES: &[&str] = &[
+    "application/rss+xml",
+    "application/atom+xml",
+    "application/feed+json",
+];
+
+/// Finds the feeds an HTML page advertises with
+/// `<link rel="alternate" type="application/rss+xml">` (or Atom / JSON Feed)
+/// tags, in document order and without duplicates. Relative hrefs resolve
+/// against `page_url`, or the page's own `<base href>` when it has one.
+pub fn discover_feed_links(html: &str, page_url: &str) -> Vec<DiscoveredFeed> {
+    let document = Html::parse_document(html);
+    let base = resolve_base_url(&document, Some(page_url));
+    let Some(sel) = selector("link[rel][href][type]") else {
+        return Vec::new();
+    };
+
+    let mut seen = HashSet::new();
+    document
+        .select(&sel)
+        .filter_map(|el| {
+            let element = el.value();
+            let is_alternate = element
+                .attr("rel")?
+                .split_ascii_whitespace()
+                .any(|rel| rel.eq_ignore_ascii_case("alternate"));
+            let feed_type = element
+                .attr("type")?
+                .split(';')
+                .next()?
+                .trim()
+                .to_ascii_lowercase();
+            let href = element.attr("href")?.trim();
+            if !is_alternate || !FEED_LINK_TYPES.contains(&feed_type.as_str()) || href.is_empty() {
+                return None;
+            }
+            Some(DiscoveredFeed {
+                href: resolve_href(base.as_ref(), href),
+                title: element
+                    .attr("title")
+                    .map(str::trim)
+                    .filter(|title| !title.is_empty())
+                    .map(str::to_string),
+                feed_type,
+            })
+        })
+        .filter(|feed| seen.insert(feed.href.clone()))
+        .collect()
+}
+
+/// Edge length assumed for `apple-touch-icon` links without `sizes`, which
+/// iOS treats as 180x180.
+const APPLE_TOUCH_ICON_DEFAULT_SIZE: u32 = 180;
+
+/// Largest edge length declared in an icon link's `sizes` attribute. `any`
+/// (a scalable icon) outranks every fixed size; missing or unparseable sizes
+/// count as zero.
+fn declared_icon_size(sizes: Option<&str>) -> Option<u32> {
+    sizes?
+        .split_ascii_whitespace()
+        .filter_map(|size| {
+            if size.eq_ignore_ascii_case("any") {
+                return Some(u32::MAX);
+            }
+            let size = size.to_ascii_lowercase();
+            let (width, height) = size.split_once('x')?;
+            Some(width.parse::<u32>().ok()?.max(height.parse().ok()?))
+        })
+        .max()
+}
+
+/// Picks the page's site icon from `<link rel="icon">`, `rel="shortcut icon"`,
+/// and `rel="apple-touch-icon"` tags, preferring the largest declared `sizes`
+/// (earlier tags win ties), resolved against `base_url`. Falls back to
+/// `/favicon.ico` on the base URL's host when the page declares no icon;
+/// `None` only when there is no icon tag and `base_url` is not a URL.
+pub fn discover_favicon(html: &str, base_url: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let base = resolve_base_url(&document, Some(base_url));
+    let declared = selector("link[rel][href]").and_then(|sel| {
+        document
+            .select(&sel)
+            .filter_map(|el| {
+                let element = el.value();
+                let rels: Vec<String> = element
+                    .attr("rel")?
+                    .split_ascii_whitespace()
+                    .map(str::to_ascii_lowercase)
+                    .collect();
+                let default_size = if rels.iter().any(|rel| rel == "apple-touch-icon") {
+                    APPLE_TOUCH_ICON_DEFAULT_SIZE
+                } else if rels.iter().any(|rel| rel == "icon") {
+                    0
+                } else {
+                    return None;
+                };
+                let href = element.attr("href")?.trim();
+                if href.is_empty() {
+                    return None;
+                }
+                let size = declared_icon_size(element.attr("sizes")).unwrap_or(default_size);
+                Some((size, href))
+            })
+            .enumerate()
+            .max_by_key(|(index, (size, _))| (*size, std::cmp::Reverse(*index)))
+            .map(|(_, (_, href))| resolve_href(base.as_ref(), href))
+    });
+    declared.or_else(|| {
+        url::Url::parse(base_url.trim())
+            .ok()?
+            .join("/favicon.ico")
+            .ok()
+            .map(|url| url.to_string())
+    })
+}
+
 /// Parses an HTML document and extracts article body text, title, authors,
-/// publish date, lead image, all images, and meta description.
+/// publish date, lead image, all images, meta description, canonical URL,
+/// and alternate links.
 ///
 /// Body text extraction tries a prioritized list of article-specific CSS
-/// selectors before falling back to generic paragraph selectors.
-pub fn extract_article_from_html(html: &str) -> ArticleExtraction {
+/// selectors before falling back to generic paragraph selectors. Alternate
+/// link targets are resolved against `page_url` when given, falling back to
+/// the document's own base, canonical, or `og:url` address.
+pub fn extract_article_from_html(html: &str, page_url: Option<&str>) -> ArticleExtraction {
     let document = Html::parse_document(html);
 
     let text = extract_text_from_selectors(
@@ -220,6 +864,17 @@ pub fn extract_article_from_html(html: &str) -> ArticleExtraction {
     let top_image = extract_top_image(&document);
     let images = extract_images(&document);
     let meta_description = extract_meta_description(&document);
+    let event = extract_json_ld_event(&document);
+    let readable_text = extract_readable_text(&document);
+    let base = resolve_base_url(&document, page_url);
+    let alternate_links = extract_alternate_links(&document, base.as_ref());
+    let amp_url = alternate_links
+        .iter()
+        .find(|link| link.rel == "amphtml")
+        .map(|link| link.href.clone());
+    let canonical_url = first_link_href(&document, "link[rel='canonical']")
+        .or_else(|| first_meta_content(&document, &["meta[property='og:url']"]))
+        .map(|href| resolve_href(base.as_ref(), &href));
 
     ArticleExtraction {
         text,
@@ -229,31 +884,34 @@ pub fn extract_article_from_html(html: &str) -> ArticleExtraction {
         top_image,
         images,
         meta_description,
+        event,
+        amp_url,
+        canonical_url,
+        alternate_links,
+        readable_text,
     }
 }
 
 /// Extracts Open Graph and Twitter image URLs from an HTML document, along
-/// with link-rel image references, ranked by source priority.
+/// with link-rel image references and the first `<article>` image as a last
+/// resort, ranked by source priority.
 pub fn extract_og_image_from_html(html: &str) -> OgImageExtraction {
     let document = Html::parse_document(html);
     let mut candidates = Vec::new();
 
     let og_images = meta_contents(&document, "meta[property='og:image']");
     for url in og_images {
-        candidates.push(ImageCandidate {
-            url,
-            source: "og:image".to_string(),
-            priority: 1,
-        });
+        candidates.push(image_candidate(url, "og:image", 1));
+    }
+
+    let secure_images = meta_contents(&document, "meta[property='og:image:secure_url']");
+    for url in secure_images {
+        candidates.push(image_candidate(url, "og:image:secure_url", 1));
     }
 
     let twitter_images = meta_contents(&document, "meta[name='twitter:image']");
     for url in twitter_images {
-        candidates.push(ImageCandidate {
-            url,
-            source: "twitter:image".to_string(),
-            priority: 2,
-        });
+        candidates.push(image_candidate(url, "twitter:image", 2));
     }
 
     if let Some(sel) = selector("link[rel='image_src']") {
@@ -263,15 +921,15 @@ pub fn extract_og_image_from_html(html: &str) -> OgImageExtraction {
                 if cleaned.is_empty() {
                     continue;
                 }
-                candidates.push(ImageCandidate {
-                    url: cleaned.to_string(),
-                    source: "link:image_src".to_string(),
-                    priority: 3,
-                });
+                candidates.push(image_candidate(cleaned.to_string(), "link:image_src", 3));
             }
         }
     }
 
+    if let Some(url) = first_article_image(&document) {
+        candidates.push(image_candidate(url, "article:img", 4));
+    }
+
     let image_url = candidates.first().map(|candidate| candidate.url.clone());
 
     OgImageExtraction {
@@ -287,7 +945,14 @@ pub fn extract_og_image_from_html(html: &str) -> OgImageExtraction {
 // type the test was ran so that you couldn't hardcode to match it making it's as univerisal as
 // possible. Future Feature.
 mod tests {
-    use super::extract_article_from_html;
+    use scraper::Html;
+
+    use super::{
+        discover_favicon, discover_feed_links, extract_article_from_html, extract_json_ld,
+        extract_og_image_from_html, extract_readable_text, infer_image_dimensions, AlternateLink,
+        DiscoveredFeed,
+    };
+    use crate::types::EventDetails;
 
     #[test]
     fn extracts_itemprop_article_body_paragraphs() {
@@ -303,7 +968,7 @@ mod tests {
         </html>
         "#;
 
-        let extracted = extract_article_from_html(html);
+        let extracted = extract_article_from_html(html, None);
 
         assert_eq!(extracted.text, "First paragraph.\n\nSecond paragraph.");
     }
@@ -322,8 +987,342 @@ mod tests {
         </html>
         "#;
 
-        let extracted = extract_article_from_html(html);
+        let extracted = extract_article_from_html(html, None);
 
         assert_eq!(extracted.text, "Primary story paragraph.");
     }
+
+    #[test]
+    fn extracts_every_valid_json_ld_block() {
+        let html = r#"
+        <html><head>
+            <script type="application/ld+json">
+            {"@context": "https://schema.org", "@type": "NewsArticle",
+             "headline": "Rates hold steady", "datePublished": "2026-03-01T09:00:00Z",
+             "author": [{"@type": "Person", "name": "A. Reporter"}]}
+            </script>
+            <script type="application/ld+json">{ "broken": </script>
+            <script type="application/json">{"@type": "NotLinkedData"}</script>
+            <script type="application/ld+json">[{"@type": "BreadcrumbList"}]</script>
+        </head><body></body></html>
+        "#;
+
+        let blocks = extract_json_ld(html);
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0]["headline"], "Rates hold steady");
+        assert_eq!(blocks[0]["author"][0]["name"], "A. Reporter");
+        assert_eq!(blocks[1][0]["@type"], "BreadcrumbList");
+    }
+
+    #[test]
+    fn extracts_json_ld_event_from_graph() {
+        let html = r#"
+        <html>
+            <head>
+                <script type="application/ld+json">{ not valid json</script>
+                <script type="application/ld+json">
+                {"@context": "https://schema.org", "@graph": [
+                    {"@type": "WebPage", "name": "Listings"},
+                    {"@type": "MusicEvent", "name": "Summer Concert",
+                     "startDate": "2026-07-01T19:00:00+02:00",
+                     "endDate": "2026-07-01T22:00:00+02:00",
+                     "location": {"@type": "Place", "name": "City Arena"}}
+                ]}
+                </script>
+            </head>
+            <body><p>Tickets on sale now.</p></body>
+        </html>
+        "#;
+
+        let extracted = extract_article_from_html(html, None);
+
+        assert_eq!(
+            extracted.event,
+            Some(EventDetails {
+                name: Some("Summer Concert".to_string()),
+                start: Some("2026-07-01T19:00:00+02:00".to_string()),
+                end: Some("2026-07-01T22:00:00+02:00".to_string()),
+                location: Some("City Arena".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn infers_dimensions_from_cdn_url_patterns() {
+        assert_eq!(
+            infer_image_dimensions("https://cdn.example.com/img/800x600/photo.jpg"),
+            (Some(800), Some(600))
+        );
+        assert_eq!(
+            infer_image_dimensions("https://cdn.example.com/photo_1200x630.jpg"),
+            (Some(1200), Some(630))
+        );
+        assert_eq!(
+            infer_image_dimensions("https://cdn.example.com/photo.jpg?w=1200&q=80"),
+            (Some(1200), None)
+        );
+        assert_eq!(
+            infer_image_dimensions("https://res.example.com/image/upload/w_640,h_360/photo.jpg"),
+            (Some(640), Some(360))
+        );
+    }
+
+    #[test]
+    fn ignores_ambiguous_dimension_patterns() {
+        assert_eq!(
+            infer_image_dimensions("https://cdn.example.com/800x600/photo_1024x768.jpg"),
+            (None, None)
+        );
+        assert_eq!(
+            infer_image_dimensions("https://cdn.example.com/800x600/photo.jpg?w=1024"),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn extracts_amp_and_alternate_links_resolved_against_base() {
+        let html = r#"
+            <html><head>
+              <link rel="canonical" href="https://news.example.com/world/story">
+              <link rel="amphtml" href="/amp/world/story">
+              <link rel="alternate" type="application/rss+xml" href="feed.xml">
+              <link rel="stylesheet" href="/style.css">
+            </head><body><p>Body</p></body></html>
+        "#;
+
+        let extracted = extract_article_from_html(html, None);
+
+        assert_eq!(
+            extracted.amp_url.as_deref(),
+            Some("https://news.example.com/amp/world/story")
+        );
+        assert_eq!(
+            extracted.alternate_links,
+            vec![
+                AlternateLink {
+                    rel: "amphtml".to_string(),
+                    href: "https://news.example.com/amp/world/story".to_string(),
+                    link_type: None,
+                },
+                AlternateLink {
+                    rel: "alternate".to_string(),
+                    href: "https://news.example.com/world/feed.xml".to_string(),
+                    link_type: Some("application/rss+xml".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_canonical_url_falling_back_to_og_url() {
+        let relative = r#"<html><head>
+              <link rel="canonical" href="/world/story-1">
+              <meta property="og:url" content="https://partner.example.net/reprint">
+            </head><body></body></html>"#;
+        let og_only = r#"<html><head>
+              <meta property="og:url" content="https://wire.example.com/story-1">
+            </head><body></body></html>"#;
+
+        assert_eq!(
+            extract_article_from_html(relative, Some("https://news.example.com/a/b"))
+                .canonical_url
+                .as_deref(),
+            Some("https://news.example.com/world/story-1")
+        );
+        assert_eq!(
+            extract_article_from_html(og_only, None)
+                .canonical_url
+                .as_deref(),
+            Some("https://wire.example.com/story-1")
+        );
+        assert_eq!(
+            extract_article_from_html("<p>No head</p>", None).canonical_url,
+            None
+        );
+    }
+
+    #[test]
+    fn collects_the_largest_srcset_candidate() {
+        let html = r#"<html><body><article>
+            <img srcset=" https://cdn.example.com/hero-800.jpg 800w,
+                          https://cdn.example.com/hero-1600.jpg 1600w ,
+                          https://cdn.example.com/hero-400.jpg 400w">
+            <img src="/thumb.jpg" srcset="/thumb.jpg 1x, /thumb@2x.jpg 2x">
+            <img src="/plain.jpg">
+        </article></body></html>"#;
+
+        assert_eq!(
+            extract_article_from_html(html, None).images,
+            vec![
+                "https://cdn.example.com/hero-1600.jpg",
+                "/thumb.jpg",
+                "/thumb@2x.jpg",
+                "/plain.jpg",
+            ]
+        );
+    }
+
+    #[test]
+    fn prefers_lazy_load_attributes_over_placeholder_src() {
+        let html = r#"<html><body><article>
+            <img data-src="real.jpg" src="placeholder.png">
+            <img data-lazy-src="/lazy.jpg" src="data:image/gif;base64,R0lGODlhAQABAAAAACw=">
+            <img src="/img/spacer.gif" data-srcset="/wide-640.jpg 640w, /wide-1280.jpg 1280w">
+            <img src="/img/blank.gif">
+        </article></body></html>"#;
+
+        let extracted = extract_article_from_html(html, None);
+
+        assert_eq!(extracted.top_image.as_deref(), Some("real.jpg"));
+        assert_eq!(
+            extracted.images,
+            vec!["real.jpg", "/lazy.jpg", "/wide-1280.jpg"]
+        );
+        assert_eq!(
+            extract_og_image_from_html(html).image_url.as_deref(),
+            Some("real.jpg")
+        );
+    }
+
+    #[test]
+    fn discovers_the_largest_declared_favicon() {
+        let html = r#"<html><head>
+              <link rel="shortcut icon" href="/favicon-16.png" sizes="16x16">
+              <link rel="icon" href="icons/icon-32.png" sizes="16x16 32x32">
+              <link rel="apple-touch-icon" href="/apple-touch-icon.png">
+              <link rel="icon" href="/icon-192.png" sizes="192x192">
+              <link rel="stylesheet" href="/big.css" sizes="999x999">
+            </head><body></body></html>"#;
+
+        assert_eq!(
+            discover_favicon(html, "https://news.example.com/section/page").as_deref(),
+            Some("https://news.example.com/icon-192.png")
+        );
+        assert_eq!(
+            discover_favicon(
+                r#"<link rel="SHORTCUT ICON" href="fav.ico"><link rel="icon" href="/other.png">"#,
+                "https://news.example.com/section/page"
+            )
+            .as_deref(),
+            Some("https://news.example.com/section/fav.ico")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_favicon_ico_on_the_host() {
+        assert_eq!(
+            discover_favicon("<p>No icons</p>", "https://news.example.com/a/b?c=d").as_deref(),
+            Some("https://news.example.com/favicon.ico")
+        );
+        assert_eq!(discover_favicon("<p>No icons</p>", "not a url"), None);
+    }
+
+    #[test]
+    fn readable_text_keeps_the_story_and_drops_page_chrome() {
+        let html = include_str!("../tests/fixtures/news_article.html");
+
+        let text = extract_readable_text(&Html::parse_document(html));
+
+        assert!(text.starts_with("The city council voted on Tuesday"));
+        assert!(text.contains("rejected by 31 votes to 9."));
+        for boilerplate in [
+            "Share this article",
+            "Subscribe today",
+            "River levels reach a record high",
+            "morning briefing",
+            "waste of money",
+            "All rights reserved",
+        ] {
+            assert!(!text.contains(boilerplate), "kept {boilerplate:?}");
+        }
+    }
+
+    #[test]
+    fn leaves_script_and_style_contents_out_of_extracted_text() {
+        let html = include_str!("../tests/fixtures/article_with_inline_styles.html");
+
+        let extracted = extract_article_from_html(html, None);
+
+        for text in [&extracted.text, &extracted.readable_text] {
+            assert!(text.starts_with("The harbour ferry returned"), "{text}");
+            assert!(
+                text.contains("Passengers queued from early morning for the first crossing"),
+                "{text}"
+            );
+            assert!(text.ends_with("end of the summer season."), "{text}");
+            for leaked in [
+                "font-family",
+                "border-left",
+                "dataLayer",
+                "departure board",
+                "Template placeholder",
+            ] {
+                assert!(!text.contains(leaked), "kept {leaked:?} in {text}");
+            }
+        }
+    }
+
+    #[test]
+    fn ranks_secure_og_images_and_falls_back_to_article_images() {
+        let html = r#"
+        <html>
+            <head>
+                <meta property="og:image:secure_url" content="https://cdn.example.com/lead.jpg">
+                <meta name="twitter:image" content="https://cdn.example.com/card.jpg">
+            </head>
+            <body>
+                <img src="/logo.png">
+                <article><p>Story</p><img src=""><img src="/inline.jpg"><img src="/second.jpg"></article>
+            </body>
+        </html>
+        "#;
+
+        let extracted = extract_og_image_from_html(html);
+
+        let ranked: Vec<(&str, &str, usize)> = extracted
+            .candidates
+            .iter()
+            .map(|c| (c.url.as_str(), c.source.as_str(), c.priority))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![
+                ("https://cdn.example.com/lead.jpg", "og:image:secure_url", 1),
+                ("https://cdn.example.com/card.jpg", "twitter:image", 2),
+                ("/inline.jpg", "article:img", 4),
+            ]
+        );
+        assert_eq!(
+            extracted.image_url.as_deref(),
+            Some("https://cdn.example.com/lead.jpg")
+        );
+    }
+
+    #[test]
+    fn discovers_advertised_feeds_with_resolved_hrefs() {
+        let html = r#"<html><head>
+            <link rel="alternate" type="application/rss+xml" title="All stories" href="/feeds/all.rss">
+            <link rel="alternate" type="application/atom+xml; charset=utf-8" href="https://cdn.example.org/atom.xml">
+            <link rel="alternate" hreflang="fr" type="text/html" href="/fr/">
+            <link rel="stylesheet" type="text/css" href="/site.css">
+            <link rel="alternate" type="application/rss+xml" href="feeds/all.rss">
+        </head><body></body></html>"#;
+
+        assert_eq!(
+            discover_feed_links(html, "https://www.example.com/"),
+            vec![
+                DiscoveredFeed {
+                    href: "https://www.example.com/feeds/all.rss".to_string(),
+                    title: Some("All stories".to_string()),
+                    feed_type: "application/rss+xml".to_string(),
+                },
+                DiscoveredFeed {
+                    href: "https://cdn.example.org/atom.xml".to_string(),
+                    title: None,
+                    feed_type: "application/atom+xml".to_string(),
+                },
+            ]
+        );
+    }
 }
@@ -0,0 +1,102 @@
+use regex::Regex;
+
+/// Caller-supplied include and exclude keyword lists, compiled into
+/// case-insensitive whole-word matchers.
+#[derive(Clone, Debug, Default)]
+pub struct KeywordFilter {
+    include: Option<Regex>,
+    exclude: Option<Regex>,
+}
+
+/// Compiles keywords into one alternation that only matches whole words or
+/// phrases, so `art` does not match inside `article`. Spaces inside a phrase
+/// match any run of whitespace. Blank keywords are dropped; returns `None`
+/// when none remain, and an error when the pattern is too large to compile.
+fn keyword_matcher(keywords: Vec<String>) -> Result<Option<Regex>, String> {
+    let alternatives: Vec<String> = keywords
+        .iter()
+        .map(|keyword| {
+            keyword
+                .split_whitespace()
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(r"\s+")
+        })
+        .filter(|keyword| !keyword.is_empty())
+        .collect();
+    if alternatives.is_empty() {
+        return Ok(None);
+    }
+    let pattern = format!(
+        r"(?i)(?:^|[^\p{{L}}\p{{N}}_])(?:{})(?:$|[^\p{{L}}\p{{N}}_])",
+        alternatives.join("|")
+    );
+    Regex::new(&pattern)
+        .map(Some)
+        .map_err(|err| format!("Invalid keyword list: {err}"))
+}
+
+impl KeywordFilter {
+    /// Builds a filter from raw keyword lists. An empty include list lets
+    /// every article through the include check.
+    pub fn new(include: Vec<String>, exclude: Vec<String>) -> Result<Self, String> {
+        Ok(Self {
+            include: keyword_matcher(include)?,
+            exclude: keyword_matcher(exclude)?,
+        })
+    }
+
+    /// Whether the filter would drop anything at all.
+    pub fn is_active(&self) -> bool {
+        self.include.is_some() || self.exclude.is_some()
+    }
+
+    /// Keeps an article whose title or description mentions an include
+    /// keyword (when any are set) and mentions no exclude keyword.
+    pub fn keeps(&self, title: &str, description: &str) -> bool {
+        let mentions = |matcher: &Regex| matcher.is_match(title) || matcher.is_match(description);
+        self.include.as_ref().is_none_or(mentions) && !self.exclude.as_ref().is_some_and(mentions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeywordFilter;
+
+    #[test]
+    fn matches_whole_words_case_insensitively() {
+        let filter = KeywordFilter::new(
+            vec!["art".to_string(), "Climate change".to_string()],
+            vec!["opinion".to_string()],
+        )
+        .expect("valid keywords");
+
+        assert!(filter.keeps("Modern ART fair opens", ""));
+        assert!(filter.keeps("Update", "New climate  change report"));
+        assert!(!filter.keeps("Read this article", "Starting today"));
+        assert!(!filter.keeps("Art review", "An opinion piece"));
+        assert!(!filter.keeps("Climate-change", "Opinion: art"));
+    }
+
+    #[test]
+    fn empty_include_list_keeps_everything_not_excluded() {
+        let filter = KeywordFilter::new(vec![" ".to_string()], vec!["sport".to_string()])
+            .expect("valid keywords");
+
+        assert!(filter.keeps("Markets rally", ""));
+        assert!(!filter.keeps("Sport roundup", ""));
+        assert!(!KeywordFilter::new(Vec::new(), Vec::new())
+            .expect("valid keywords")
+            .is_active());
+    }
+
+    #[test]
+    fn reports_keyword_lists_too_large_to_compile() {
+        // Each of the three case forms of this letter becomes its own state.
+        let keywords = vec!["Ǆ".repeat(200_000)];
+
+        let err = KeywordFilter::new(keywords, Vec::new()).expect_err("pattern too large");
+
+        assert!(err.starts_with("Invalid keyword list:"), "{err}");
+    }
+}
@@ -8,9 +8,12 @@
 //! The crate provides:
 //!
 //! - **Feed ingestion**: Concurrent RSS/Atom fetching and parsing with
-//!   configurable concurrency limits.
+//!   configurable concurrency limits, a fetch-only variant that returns raw
+//!   feed bodies, plus merging of batched results, CSV export of parsed
+//!   articles, and OPML import and export of subscriptions.
 //! - **Article extraction**: HTML-based extraction of article bodies, Open
-//!   Graph images, and metadata from raw web pages.
+//!   Graph images, and metadata from raw web pages, one at a time or as a
+//!   parallel batch.
 //! - **Deduplication**: MinHash-based duplicate detection and deduplication of
 //!   article groups using character n-gram shingling.
 //! - **Topic clustering**: Lexical clustering of articles by title keyword
@@ -24,65 +27,697 @@
 //! - **Country mentions**: High-performance country name extraction from
 //!   article text using Aho-Corasick automata and multi-token alias matching.
 
+use std::collections::HashMap;
+
+use once_cell::sync::OnceCell;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBytes, PyDict};
+use rayon::prelude::*;
 use tokio::runtime::Runtime;
 
 mod algorithms;
 mod blindspot;
+mod cache;
+mod cancel;
 mod cleaner;
+mod client;
+mod concurrency;
 mod country_mentions;
+mod export;
 mod feed_rank;
 mod fetcher;
 mod gdelt;
 mod html_extract;
+mod keywords;
+mod merge;
+mod opml;
 mod parser;
+mod progress;
+mod sentiment;
 mod topics;
 mod types;
 
 use crate::algorithms::{
     deduplicate_article_groups, minhash_duplicate_pairs, sentence_diff, text_similarity,
 };
+use crate::cache::FeedCache;
+use crate::cancel::CancelToken;
+use crate::client::FeedClient;
+use crate::export::articles_to_csv;
 use crate::feed_rank::rank_articles;
+use crate::fetcher::{build_request_headers, fetch_all, fetch_html_pages};
 use crate::gdelt::{filter_gdelt_by_domain, parse_gdelt_csv};
-use crate::html_extract::{extract_article_from_html, extract_og_image_from_html};
-use crate::parser::parse_sources;
-use crate::types::{ensure_source_requests, parse_result_to_pydict};
+use crate::html_extract::{
+    discover_favicon, discover_feed_links, extract_article_from_html, extract_json_ld,
+    extract_og_image_from_html, ArticleExtraction, OgImageExtraction,
+};
+use crate::keywords::KeywordFilter;
+use crate::merge::merge_results;
+use crate::opml::{generate_opml_document, parse_opml_feeds};
+use crate::parser::{parse_sources, validate_fetch_result};
+use crate::progress::{IngestProgress, SourceProgressCallback};
+use crate::sentiment::SentimentLexicon;
+use crate::types::{
+    ensure_source_requests, event_to_pydict, extract_previous_source_stats,
+    fetch_results_to_pydict, parse_result_to_pydict, CacheValidators, DescriptionFormat,
+    DuplicateGuidPolicy, FeedCredentials, FeedValidation, ParseOptions,
+};
 
 /// Fetches and parses multiple RSS/Atom feeds concurrently and returns all
 /// extracted articles, per-source statistics, and timing metrics.
 ///
 /// Accepts a list of named source groups (each with one or more feed URLs) and
 /// an optional maximum concurrency limit. Returns a Python dictionary with
-/// keys `articles`, `source_stats`, and `metrics`.
-#[pyfunction(signature = (sources, max_concurrent=None, timeout_ms=None))]
+/// keys `articles`, `source_stats`, and `metrics`, or the same structure as a
+/// JSON string when `as_json` is set.
+///
+/// Output is ordered the same way for the same input, however the fetches
+/// finish: `source_stats` (and `articles_by_source`) are keyed in name
+/// order, each source's `sub_feeds` follow its URL list, and articles follow
+/// the source list before any date sort, which keeps ties in that order.
+///
+/// With `allow_local_urls=True`, a source may also list `file://` paths and
+/// `data:` URLs (plain or `;base64`) carrying a feed inline. These are read
+/// without any network access and parsed the same way; an unreadable file
+/// becomes a normal sub-feed error. Leave it off for user-supplied URLs:
+/// without it such URLs fail with an `other` error instead of reading the
+/// local disk.
+///
+/// Optional keyword arguments:
+///
+/// - `previous_article_ids`: feed URL to article IDs seen on the last run;
+///   matching sub-feeds report a `new_article_count`.
+/// - `strip_decorative_chars`: remove control and zero-width characters from
+///   titles, plus emoji when `strip_emoji` is also set.
+/// - `extract_events`: add an `event` dict to articles from event feeds.
+/// - `user_agents`: rotate these User-Agent strings per host instead of the
+///   fixed default.
+/// - `extract_full_content`: add the cleaned full content body as
+///   `content_text`.
+/// - `group_by_source`: also return `articles_by_source`, mapping each source
+///   name to its articles newest first.
+/// - `raw_xml_sources`: names of sources whose decoded feed bodies are also
+///   returned in `raw_xml`, keyed by feed URL, for bespoke parsing of
+///   extensions. Only these sources' bodies are kept.
+/// - `feed_bodies`: feed URL to the `bytes` of a body already downloaded
+///   elsewhere. Those URLs are decoded and parsed without a request; the
+///   rest are fetched as usual, and only those requests count toward the
+///   `fetch_*` metrics. `parse_feed_bodies` is a shorthand for runs made
+///   only of supplied bodies.
+/// - `unzip_archives`: unpack ZIP responses that hold a single XML entry.
+/// - `random_seed`: seed the random jitter in fetch timing for reproducible runs.
+/// - `infer_category_from_url`: when an entry has no category, guess one from
+///   the feed URL path and set `category_inferred`.
+/// - `parse_deadline_secs`: cap time spent parsing; unfinished sources keep
+///   their partial articles and report `parse_timeout`, and their sub-feeds
+///   report status `"skipped"` or `"partial"`. The deadline is checked between
+///   sub-feeds and entries, so a single feed document's parse is never
+///   interrupted and can run past it.
+/// - `blocked_domains`: drop articles whose link host is one of these domains
+///   or a subdomain of one, counted in `articles_blocked`.
+/// - `previous_source_stats`: the previous run's `source_stats`; only sources
+///   whose status or article count changed are returned, and the rest are
+///   listed in `unchanged_sources`.
+/// - `progress`: an `IngestProgress` handle whose counters (`feeds_done`,
+///   `articles_parsed`, `bytes_fetched`) are updated while the run proceeds.
+/// - `source_progress_callback`: a callable invoked with `(source_name,
+///   status, sources_done)` as soon as every feed request of a source has
+///   finished, while other sources are still fetching. `status` is
+///   `"success"`, `"warning"` (some sub-feeds failed), or `"error"`, and
+///   `sources_done` counts the sources finished so far. The third argument
+///   is not a running article count: articles are only parsed once all
+///   fetches end, so use
+///   `progress.articles_parsed` or the returned `source_stats` for article
+///   counts. Exceptions raised by the callback are reported as unraisable
+///   and do not stop the run.
+/// - `prefer_full_content`: build `description` from the full content body
+///   (`content:encoded` / Atom `content`) when the entry has one, falling
+///   back to the summary. By default the summary wins.
+/// - `cancel_token`: a `CancelToken`; cancelling it from another thread stops
+///   new requests, drops in-flight ones (their sub-feeds fail with
+///   `Cancelled`), and returns whatever was fetched, with
+///   `metrics["cancelled"]` set.
+/// - `accept_statuses`: HTTP status codes whose body is parsed as a feed
+///   even though they would otherwise count as errors.
+/// - `compute_sentiment`: score each title and description against
+///   `positive_words` and `negative_words` into `sentiment_score`.
+/// - `detect_service_errors`: flag sub-feeds that parse with no entries but
+///   carry an error-like title as `service_error_suspect`.
+/// - `duplicate_guid_policy`: `"ignore"` (default), `"link"` to replace a
+///   guid repeated within a sub-feed with the entry link, or `"flag"` to keep
+///   it and set `duplicate_guid` on the repeats. Repeats are always counted
+///   in the sub-feed's `duplicate_guid_count`.
+/// - `inline_icons`: download each source's feed icon (up to 32 KiB) and
+///   return it as a base64 data URI in `feed_icon_data`.
+/// - `cache_validators`: feed URL to `{"etag": ..., "last_modified": ...}`
+///   from the previous run's sub-feed stats. Those URLs are requested
+///   conditionally, and a `304` yields a sub-feed with status `not_modified`
+///   and no articles. Every sub-feed reports fresh `etag` and
+///   `last_modified` values to persist.
+/// - `cache`: a `FeedCache` whose validators are sent like
+///   `cache_validators` (entries there win for the same URL), and which is
+///   updated in place with each response's validators and `304` streaks.
+///   A feed that fails to parse, or that the parse deadline skipped or cut
+///   short, keeps its previous validators.
+/// - `per_host_max`: cap concurrent requests to any single host, within the
+///   overall `max_concurrent` limit.
+/// - `adaptive_concurrency`: treat `max_concurrent` as a ceiling, starting
+///   at half of it. Each round of requests without trouble adds one slot,
+///   while a timeout, connection failure, 429, or 5xx response, or a p95
+///   latency above half the request timeout, halves the limit.
+///   `metrics["effective_concurrency"]` reports where it settled.
+/// - `max_redirects`: most redirects followed per feed URL (default 10);
+///   going past it fails the sub-feed with a "Too many redirects" error.
+///   Each sub-feed's `redirects` lists the URLs it was redirected through,
+///   so feeds that have moved can be updated.
+/// - `max_retries`: retry connection errors, timeouts, 429, and 5xx
+///   responses up to this many times (default 0), with exponential backoff
+///   from `retry_base_delay_ms` (default 500) plus jitter. Exhausted retries
+///   note the attempt count in the error message. A 429 or 503 carrying
+///   `Retry-After` waits that long instead, and fails at once when the
+///   server asks for more than a minute. Each attempt gets the full
+///   `timeout`, and a retry whose wait would be longer than `timeout` is not
+///   made. Each sub-feed reports the requested delay as `retry_after_secs`
+///   either way.
+/// - `dedupe`: drop articles whose `guid` already appeared in an earlier
+///   source or sub-feed. A guid that is not a URL only matches within its
+///   own source, since publishers pick such ids independently. Entries
+///   without a guid are keyed by their normalized link (lowercased scheme
+///   and host, no trailing slash, no tracking parameters); `article_count`
+///   reflects what remains.
+/// - `dedupe_within_source`: merge a source's sub-feeds, dropping entries an
+///   earlier sub-feed already returned (same key as `dedupe`). The first
+///   occurrence wins; the source's `article_count` counts unique stories and
+///   `merged_duplicate_count` how many were dropped, while each sub-feed's
+///   `article_count` still reports what it contributed before the merge.
+/// - `tracking_params`: query parameters stripped for `dedupe` and
+///   `strip_tracking_params`, replacing the built-in `utm_*`/click-id list.
+///   A trailing `*` matches a prefix.
+/// - `strip_tracking_params`: remove tracking query parameters from each
+///   article's `link`, keeping other parameters and the fragment.
+/// - `preserve_paragraphs`: keep paragraph breaks in descriptions as blank
+///   lines instead of flattening them to a single line.
+/// - `keep_ideographic_spaces`: leave the ideographic space (U+3000), thin
+///   space, and narrow no-break space in titles and text descriptions as
+///   written, including at either end, for CJK sources that use them for
+///   layout. Other whitespace is still collapsed.
+/// - `summary_max_chars`: fill each article's `summary` with its description
+///   as plain text, cut at the last word boundary within this many
+///   characters and ending in `…` when shortened. `description` is returned
+///   in full either way; `summary` is empty when unset.
+/// - `truncation_phrases`: description endings such as `"read more"` that
+///   mark an article `likely_truncated`, replacing the built-in list. Articles
+///   are also flagged when the description is shorter than the title or is a
+///   short paragraph followed only by a link.
+/// - `sort_by_date`: return `articles` newest first by parsed publish time
+///   instead of per-source order; undated articles and unparseable dates
+///   come last.
+/// - `credentials`: feed URL to `{"username": ..., "password": ...}` for
+///   HTTP Basic auth or `{"token": ...}` for a bearer token. Any other shape
+///   raises `ValueError`. Credentials are only sent to their own URL (and
+///   dropped on redirects to another host) and never appear in errors.
+/// - `normalize_dates_utc`: format every `published` value as UTC with a
+///   `Z` suffix (e.g. `2026-02-03T08:00:00Z`). The instant is unchanged,
+///   including the "now" fallback for undated entries.
+/// - `max_articles_per_source`: keep only each source's newest N articles.
+///   The source's `article_count` still reports the full parsed count, and
+///   `returned_count` what was kept.
+/// - `client`: a `FeedClient` whose connection pool, User-Agent, and default
+///   timeout to use. Without one, a process-wide pooled client is shared.
+/// - `since_rfc3339`: drop entries published before this RFC 3339 instant.
+///   Entries without a date are kept; each source's `filtered_out_count`
+///   reports how many were dropped. An invalid timestamp raises
+///   `ValueError`.
+/// - `include_keywords` / `exclude_keywords`: keep only articles whose title
+///   or description mentions an include keyword (any, when the list is
+///   empty) and no exclude keyword. Matching is case-insensitive on whole
+///   words, so `art` does not match `article`. Each source's
+///   `keyword_filtered_count` reports how many were dropped. Lists too large
+///   to compile raise `ValueError`.
+/// - `description_format`: `"text"` (default) for plain descriptions, or
+///   `"markdown"` to keep links as `[text](url)`, bold as `**`, italics as
+///   `_`, list items as `- ` lines, and paragraphs as blank lines. Markdown
+///   already keeps paragraph breaks, so `preserve_paragraphs` is ignored.
+///   `"safe_html"` keeps only `p`, `a`, `strong`, `em`, `ul`, `ol`, `li`,
+///   `blockquote`, and `br`, with `href` the sole attribute and
+///   `javascript:` links removed, for rendering descriptions as HTML.
+///   Unknown names raise `ValueError`.
+/// - `timeout_secs`: per-request timeout in whole seconds for this call, for
+///   callers that think in seconds rather than `timeout_ms`. `timeout_ms`
+///   wins when both are set; 0 or `None` keeps the client's default (25
+///   seconds without a `FeedClient`).
+/// - `headers`: extra request headers sent with every feed request, such as
+///   an `Accept` header for publishers that serve HTML by default.
+///   `Authorization`, `Cookie`, `Proxy-Authorization`, and `WWW-Authenticate`
+///   are dropped once a redirect leaves the feed's origin.
+/// - `user_agent`: User-Agent for every feed request, taking precedence over
+///   `user_agents` rotation and the client default. Invalid header names or
+///   values in either raise `ValueError`.
+/// - `as_json`: return the result as one JSON string with the same shape as
+///   the dict, skipping the per-field Python object churn for callers that
+///   re-serialize anyway. `parse_feeds_parallel_json` is a shorthand.
+#[pyfunction(signature = (
+    sources,
+    max_concurrent=None,
+    timeout_ms=None,
+    previous_article_ids=None,
+    strip_decorative_chars=false,
+    strip_emoji=false,
+    extract_events=false,
+    user_agents=None,
+    extract_full_content=false,
+    group_by_source=false,
+    unzip_archives=false,
+    random_seed=None,
+    infer_category_from_url=false,
+    parse_deadline_secs=None,
+    blocked_domains=None,
+    previous_source_stats=None,
+    progress=None,
+    accept_statuses=None,
+    compute_sentiment=false,
+    positive_words=None,
+    negative_words=None,
+    detect_service_errors=false,
+    duplicate_guid_policy=None,
+    inline_icons=false,
+    cache_validators=None,
+    per_host_max=None,
+    max_retries=0,
+    retry_base_delay_ms=None,
+    dedupe=false,
+    tracking_params=None,
+    preserve_paragraphs=false,
+    sort_by_date=false,
+    max_articles_per_source=None,
+    client=None,
+    since_rfc3339=None,
+    include_keywords=None,
+    exclude_keywords=None,
+    description_format=None,
+    timeout_secs=None,
+    headers=None,
+    user_agent=None,
+    as_json=false,
+    source_progress_callback=None,
+    cancel_token=None,
+    prefer_full_content=false,
+    strip_tracking_params=false,
+    normalize_dates_utc=false,
+    credentials=None,
+    max_redirects=None,
+    dedupe_within_source=false,
+    adaptive_concurrency=false,
+    raw_xml_sources=None,
+    cache=None,
+    keep_ideographic_spaces=false,
+    summary_max_chars=None,
+    truncation_phrases=None,
+    feed_bodies=None,
+    allow_local_urls=false,
+))]
+#[allow(clippy::too_many_arguments)]
 fn parse_feeds_parallel<'py>(
     py: Python<'py>,
     sources: Vec<(String, Vec<String>)>,
     max_concurrent: Option<usize>,
     timeout_ms: Option<u64>,
-) -> PyResult<Bound<'py, PyDict>> {
-    let runtime = Runtime::new().map_err(|err| {
+    previous_article_ids: Option<HashMap<String, Vec<String>>>,
+    strip_decorative_chars: bool,
+    strip_emoji: bool,
+    extract_events: bool,
+    user_agents: Option<Vec<String>>,
+    extract_full_content: bool,
+    group_by_source: bool,
+    unzip_archives: bool,
+    random_seed: Option<u64>,
+    infer_category_from_url: bool,
+    parse_deadline_secs: Option<u64>,
+    blocked_domains: Option<Vec<String>>,
+    previous_source_stats: Option<HashMap<String, Bound<'py, PyDict>>>,
+    progress: Option<PyRef<'py, IngestProgress>>,
+    accept_statuses: Option<Vec<u16>>,
+    compute_sentiment: bool,
+    positive_words: Option<Vec<String>>,
+    negative_words: Option<Vec<String>>,
+    detect_service_errors: bool,
+    duplicate_guid_policy: Option<String>,
+    inline_icons: bool,
+    cache_validators: Option<HashMap<String, HashMap<String, Option<String>>>>,
+    per_host_max: Option<usize>,
+    max_retries: u32,
+    retry_base_delay_ms: Option<u64>,
+    dedupe: bool,
+    tracking_params: Option<Vec<String>>,
+    preserve_paragraphs: bool,
+    sort_by_date: bool,
+    max_articles_per_source: Option<usize>,
+    client: Option<PyRef<'py, FeedClient>>,
+    since_rfc3339: Option<String>,
+    include_keywords: Option<Vec<String>>,
+    exclude_keywords: Option<Vec<String>>,
+    description_format: Option<String>,
+    timeout_secs: Option<u64>,
+    headers: Option<HashMap<String, String>>,
+    user_agent: Option<String>,
+    as_json: bool,
+    source_progress_callback: Option<Py<PyAny>>,
+    cancel_token: Option<PyRef<'py, CancelToken>>,
+    prefer_full_content: bool,
+    strip_tracking_params: bool,
+    normalize_dates_utc: bool,
+    credentials: Option<HashMap<String, HashMap<String, String>>>,
+    max_redirects: Option<usize>,
+    dedupe_within_source: bool,
+    adaptive_concurrency: bool,
+    raw_xml_sources: Option<Vec<String>>,
+    cache: Option<PyRef<'py, FeedCache>>,
+    keep_ideographic_spaces: bool,
+    summary_max_chars: Option<usize>,
+    truncation_phrases: Option<Vec<String>>,
+    feed_bodies: Option<HashMap<String, Bound<'py, PyBytes>>>,
+    allow_local_urls: bool,
+) -> PyResult<PyObject> {
+    let runtime = shared_runtime()?;
+    let since = since_rfc3339
+        .map(|value| {
+            chrono::DateTime::parse_from_rfc3339(value.trim())
+                .map(|since| since.to_utc())
+                .map_err(|err| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Invalid since_rfc3339 {value:?}: {err}"
+                    ))
+                })
+        })
+        .transpose()?;
+    let duplicate_guid_policy = match duplicate_guid_policy.as_deref() {
+        None => DuplicateGuidPolicy::default(),
+        Some(name) => DuplicateGuidPolicy::from_name(name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown duplicate_guid_policy: {name}"
+            ))
+        })?,
+    };
+    let description_format = match description_format.as_deref() {
+        None => DescriptionFormat::default(),
+        Some(name) => DescriptionFormat::from_name(name).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unknown description_format: {name}"
+            ))
+        })?,
+    };
+    let request_headers = build_request_headers(headers.unwrap_or_default(), user_agent)
+        .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let keyword_filter = KeywordFilter::new(
+        include_keywords.unwrap_or_default(),
+        exclude_keywords.unwrap_or_default(),
+    )
+    .map_err(PyErr::new::<pyo3::exceptions::PyValueError, _>)?;
+    let credentials = credentials
+        .unwrap_or_default()
+        .into_iter()
+        .map(
+            |(url, fields)| match FeedCredentials::from_fields(&fields) {
+                Ok(credentials) => Ok((url, credentials)),
+                Err(reason) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Invalid credentials for {url}: {reason}"
+                ))),
+            },
+        )
+        .collect::<PyResult<HashMap<_, _>>>()?;
+    let source_requests = ensure_source_requests(sources);
+    let limit = max_concurrent.unwrap_or(32).max(1);
+    let timeout_ms = timeout_ms.or(timeout_secs
+        .filter(|&secs| secs > 0)
+        .map(|secs| secs.saturating_mul(1_000)));
+    let request_timeout = request_timeout(timeout_ms, client.as_deref());
+    let feed_cache = cache.map(|handle| handle.store());
+    let mut validators = feed_cache
+        .as_ref()
+        .map(|store| store.validators())
+        .unwrap_or_default();
+    validators.extend(
+        cache_validators
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(url, fields)| {
+                let field = |name: &str| fields.get(name).cloned().flatten();
+                let validators = CacheValidators {
+                    etag: field("etag"),
+                    last_modified: field("last_modified"),
+                };
+                (url, validators)
+            }),
+    );
+    let options = ParseOptions {
+        previous_article_ids: previous_article_ids
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(url, ids)| (url, ids.into_iter().collect()))
+            .collect(),
+        strip_decorative_chars,
+        strip_emoji,
+        extract_events,
+        user_agents: user_agents
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|agent| !agent.trim().is_empty())
+            .collect(),
+        request_headers,
+        extract_full_content,
+        prefer_full_content,
+        group_by_source,
+        raw_xml_sources: raw_xml_sources.unwrap_or_default().into_iter().collect(),
+        feed_bodies: feed_bodies
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(url, bytes)| (url, bytes.as_bytes().to_vec()))
+            .collect(),
+        unzip_archives,
+        random_seed,
+        allow_local_urls,
+        infer_category_from_url,
+        parse_deadline: parse_deadline_secs.map(std::time::Duration::from_secs),
+        blocked_domains: blocked_domains
+            .unwrap_or_default()
+            .into_iter()
+            .map(|domain| domain.trim().trim_matches('.').to_lowercase())
+            .filter(|domain| !domain.is_empty())
+            .collect(),
+        previous_source_stats: previous_source_stats
+            .map(extract_previous_source_stats)
+            .transpose()?,
+        progress: progress.map(|handle| handle.counters()),
+        source_progress: source_progress_callback.map(SourceProgressCallback::from_python),
+        cancel: cancel_token.map(|handle| handle.token()),
+        accept_statuses: accept_statuses.unwrap_or_default().into_iter().collect(),
+        compute_sentiment,
+        sentiment_lexicon: SentimentLexicon::new(
+            positive_words.unwrap_or_default(),
+            negative_words.unwrap_or_default(),
+        ),
+        detect_service_errors,
+        duplicate_guid_policy,
+        inline_icons,
+        cache_validators: validators,
+        feed_cache,
+        per_host_max,
+        adaptive_concurrency,
+        max_redirects,
+        max_retries,
+        retry_base_delay: std::time::Duration::from_millis(retry_base_delay_ms.unwrap_or(500)),
+        dedupe,
+        dedupe_within_source,
+        tracking_params,
+        strip_tracking_params,
+        preserve_paragraphs,
+        keep_ideographic_spaces,
+        summary_max_chars,
+        truncation_phrases,
+        sort_by_date,
+        normalize_dates_utc,
+        credentials,
+        max_articles_per_source,
+        client: client.map(|handle| handle.client()),
+        since,
+        keyword_filter,
+        description_format,
+    };
+
+    // Everything the run needs is owned Rust data by now, so other Python
+    // threads can proceed until the result dict is built.
+    let result = py.allow_threads(|| {
+        runtime.block_on(parse_sources(
+            source_requests,
+            limit,
+            request_timeout,
+            &options,
+        ))
+    });
+    if as_json {
+        let json = serde_json::to_string(&result).map_err(|err| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Failed to serialize parse result: {err}"
+            ))
+        })?;
+        return Ok(json.into_py(py));
+    }
+    Ok(parse_result_to_pydict(py, &result)?.into_any().unbind())
+}
+
+/// Same as `parse_feeds_parallel`, accepting all of its keyword arguments,
+/// but returns the result as a JSON string instead of a dict.
+#[pyfunction(signature = (sources, **kwargs))]
+fn parse_feeds_parallel_json<'py>(
+    py: Python<'py>,
+    sources: Vec<(String, Vec<String>)>,
+    kwargs: Option<Bound<'py, PyDict>>,
+) -> PyResult<PyObject> {
+    let kwargs = kwargs.unwrap_or_else(|| PyDict::new_bound(py));
+    kwargs.set_item("as_json", true)?;
+    wrap_pyfunction_bound!(parse_feeds_parallel, py)?
+        .call((sources,), Some(&kwargs))
+        .map(Bound::unbind)
+}
+
+/// Parses feed bodies the caller already downloaded, without fetching.
+///
+/// `bodies` is a list of `(source_name, url, bytes)` tuples; entries sharing
+/// a source name are merged into one source as its sub-feeds, in the order
+/// given. Returns the same dict as `parse_feeds_parallel`, with
+/// `fetch_duration_ms` and `fetch_attempts` at zero, and accepts all of its
+/// keyword arguments
+/// except that `feed_bodies` is built from `bodies`.
+#[pyfunction(signature = (bodies, **kwargs))]
+fn parse_feed_bodies<'py>(
+    py: Python<'py>,
+    bodies: Vec<(String, String, Bound<'py, PyBytes>)>,
+    kwargs: Option<Bound<'py, PyDict>>,
+) -> PyResult<PyObject> {
+    let mut sources: Vec<(String, Vec<String>)> = Vec::new();
+    let feed_bodies = PyDict::new_bound(py);
+    for (source_name, url, bytes) in bodies {
+        match sources.iter_mut().find(|(name, _)| *name == source_name) {
+            Some((_, urls)) => urls.push(url.clone()),
+            None => sources.push((source_name, vec![url.clone()])),
+        }
+        feed_bodies.set_item(url, bytes)?;
+    }
+    let kwargs = kwargs.unwrap_or_else(|| PyDict::new_bound(py));
+    kwargs.set_item("feed_bodies", feed_bodies)?;
+    wrap_pyfunction_bound!(parse_feeds_parallel, py)?
+        .call((sources,), Some(&kwargs))
+        .map(Bound::unbind)
+}
+
+static RUNTIME: OnceCell<Runtime> = OnceCell::new();
+
+/// The multi-threaded Tokio runtime every fetch runs on, started on first
+/// use. Reusing it avoids spinning up worker threads per call and keeps
+/// pooled connections tied to a live reactor between calls.
+fn shared_runtime() -> PyResult<&'static Runtime> {
+    RUNTIME.get_or_try_init(Runtime::new).map_err(|err| {
         PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
             "Failed to start Tokio runtime: {err}"
         ))
-    })?;
-    let source_requests = ensure_source_requests(sources);
-    let limit = max_concurrent.unwrap_or(32).max(1);
-    let request_timeout = std::time::Duration::from_millis(timeout_ms.unwrap_or(25_000).max(1));
+    })
+}
 
-    let result = runtime.block_on(parse_sources(source_requests, limit, request_timeout));
-    parse_result_to_pydict(py, &result)
+/// Per-request timeout: the call's `timeout_ms`, else the client's default,
+/// else 25 seconds.
+fn request_timeout(timeout_ms: Option<u64>, client: Option<&FeedClient>) -> std::time::Duration {
+    timeout_ms
+        .map(|ms| std::time::Duration::from_millis(ms.max(1)))
+        .or_else(|| client.and_then(FeedClient::timeout))
+        .unwrap_or(std::time::Duration::from_millis(25_000))
+}
+
+/// Fetches every feed URL concurrently without parsing the responses.
+///
+/// Takes the same `sources`, `max_concurrent`, `timeout_ms`, `client`, and
+/// `allow_local_urls` inputs as `parse_feeds_parallel` and returns a dict
+/// mapping each URL to `{status, body, error}`, where `status` is the HTTP
+/// status code and `body` the decoded response text of a successful fetch.
+#[pyfunction(signature = (
+    sources,
+    max_concurrent=None,
+    timeout_ms=None,
+    client=None,
+    allow_local_urls=false,
+))]
+fn fetch_feeds_parallel<'py>(
+    py: Python<'py>,
+    sources: Vec<(String, Vec<String>)>,
+    max_concurrent: Option<usize>,
+    timeout_ms: Option<u64>,
+    client: Option<PyRef<'py, FeedClient>>,
+    allow_local_urls: bool,
+) -> PyResult<Bound<'py, PyDict>> {
+    let runtime = shared_runtime()?;
+    let limit = max_concurrent.unwrap_or(32).max(1);
+    let request_timeout = request_timeout(timeout_ms, client.as_deref());
+    let options = ParseOptions {
+        client: client.map(|handle| handle.client()),
+        allow_local_urls,
+        ..Default::default()
+    };
+    let source_requests = ensure_source_requests(sources);
+    let results = py.allow_threads(|| {
+        runtime.block_on(fetch_all(source_requests, limit, request_timeout, &options))
+    });
+    fetch_results_to_pydict(py, &results)
 }
 
 /// Extracts article body text, title, authors, publish date, top image, all
 /// images, and meta description from a raw HTML string.
 ///
 /// Returns a Python dictionary with keys `text`, `title`, `authors`,
-/// `publish_date`, `top_image`, `images`, and `meta_description`.
+/// `publish_date`, `top_image`, `images`, `meta_description`, `event`
+/// (a nested dict when the page carries a JSON-LD `Event`, else `None`),
+/// `amp_url`, `canonical_url` (from `<link rel="canonical">`, else
+/// `og:url`), `alternate_links` (dicts with `rel`, `href`, and `type`), and
+/// `readable_text` (the best content block with page chrome removed).
+///
+/// The optional `base_url` is the page address that relative link targets
+/// resolve against; without it the document's own base or canonical URL is
+/// used.
+#[pyfunction(signature = (html, base_url=None))]
+fn extract_article_html<'py>(
+    py: Python<'py>,
+    html: String,
+    base_url: Option<String>,
+) -> PyResult<Bound<'py, PyDict>> {
+    article_extraction_to_pydict(py, extract_article_from_html(&html, base_url.as_deref()))
+}
+
+/// Runs `extract_article_html` over many HTML strings at once, parsing them
+/// in parallel with the GIL released.
+///
+/// Returns a list of the same dicts in input order, one per string; an
+/// empty or unparseable string yields a dict of empty fields so indices
+/// stay aligned with `htmls`.
 #[pyfunction]
-fn extract_article_html<'py>(py: Python<'py>, html: String) -> PyResult<Bound<'py, PyDict>> {
-    let result = extract_article_from_html(&html);
+fn extract_articles_batch(
+    py: Python<'_>,
+    htmls: Vec<String>,
+) -> PyResult<Bound<'_, pyo3::types::PyList>> {
+    let extractions: Vec<ArticleExtraction> = py.allow_threads(|| {
+        htmls
+            .par_iter()
+            .map(|html| extract_article_from_html(html, None))
+            .collect()
+    });
+    let list = pyo3::types::PyList::empty_bound(py);
+    for extraction in extractions {
+        list.append(article_extraction_to_pydict(py, extraction)?)?;
+    }
+    Ok(list)
+}
+
+fn article_extraction_to_pydict(
+    py: Python<'_>,
+    result: ArticleExtraction,
+) -> PyResult<Bound<'_, PyDict>> {
     let dict = PyDict::new_bound(py);
     dict.set_item("text", result.text)?;
     dict.set_item("title", result.title)?;
@@ -91,6 +726,22 @@ fn extract_article_html<'py>(py: Python<'py>, html: String) -> PyResult<Bound<'p
     dict.set_item("top_image", result.top_image)?;
     dict.set_item("images", result.images)?;
     dict.set_item("meta_description", result.meta_description)?;
+    match &result.event {
+        Some(event) => dict.set_item("event", event_to_pydict(py, event)?)?,
+        None => dict.set_item("event", py.None())?,
+    }
+    dict.set_item("amp_url", result.amp_url)?;
+    dict.set_item("canonical_url", result.canonical_url)?;
+    let alternate_links = pyo3::types::PyList::empty_bound(py);
+    for link in result.alternate_links {
+        let item = PyDict::new_bound(py);
+        item.set_item("rel", link.rel)?;
+        item.set_item("href", link.href)?;
+        item.set_item("type", link.link_type)?;
+        alternate_links.append(item)?;
+    }
+    dict.set_item("alternate_links", alternate_links)?;
+    dict.set_item("readable_text", result.readable_text)?;
     Ok(dict)
 }
 
@@ -98,10 +749,14 @@ fn extract_article_html<'py>(py: Python<'py>, html: String) -> PyResult<Bound<'p
 /// a ranked list of image candidates from multiple sources.
 ///
 /// Returns a Python dictionary with keys `image_url` and `candidates`.
-/// Each candidate includes `url`, `source`, and `priority` fields.
+/// Each candidate includes `url`, `source`, and `priority` fields, plus
+/// `width` and `height` when they can be inferred from the image URL.
 #[pyfunction]
 fn extract_og_image_html<'py>(py: Python<'py>, html: String) -> PyResult<Bound<'py, PyDict>> {
-    let result = extract_og_image_from_html(&html);
+    og_image_to_pydict(py, extract_og_image_from_html(&html))
+}
+
+fn og_image_to_pydict(py: Python<'_>, result: OgImageExtraction) -> PyResult<Bound<'_, PyDict>> {
     let dict = PyDict::new_bound(py);
     dict.set_item("image_url", result.image_url)?;
 
@@ -111,19 +766,270 @@ fn extract_og_image_html<'py>(py: Python<'py>, html: String) -> PyResult<Bound<'
         item.set_item("url", candidate.url)?;
         item.set_item("source", candidate.source)?;
         item.set_item("priority", candidate.priority)?;
+        item.set_item("width", candidate.width)?;
+        item.set_item("height", candidate.height)?;
         candidates.append(item)?;
     }
     dict.set_item("candidates", candidates)?;
     Ok(dict)
 }
 
+/// Parses every `<script type="application/ld+json">` block in an HTML
+/// document, such as schema.org `NewsArticle` metadata.
+///
+/// Returns a list with one parsed value (usually a dict) per block, in
+/// document order. Blocks that are not valid JSON are skipped.
+#[pyfunction]
+fn extract_json_ld_html(py: Python<'_>, html: String) -> PyResult<Bound<'_, pyo3::types::PyList>> {
+    let blocks = py.allow_threads(|| extract_json_ld(&html));
+    let list = pyo3::types::PyList::empty_bound(py);
+    for block in &blocks {
+        list.append(json_value_to_py(py, block)?)?;
+    }
+    Ok(list)
+}
+
+/// Converts a JSON value into the matching Python object: dicts, lists,
+/// strings, ints (floats when out of `i64`/`u64` range), bools, and `None`.
+fn json_value_to_py(py: Python<'_>, value: &serde_json::Value) -> PyResult<PyObject> {
+    use serde_json::Value;
+
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(flag) => flag.into_py(py),
+        Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(int), _) => int.into_py(py),
+            (None, Some(int)) => int.into_py(py),
+            _ => number.as_f64().unwrap_or(f64::NAN).into_py(py),
+        },
+        Value::String(text) => text.into_py(py),
+        Value::Array(items) => {
+            let list = pyo3::types::PyList::empty_bound(py);
+            for item in items {
+                list.append(json_value_to_py(py, item)?)?;
+            }
+            list.into_py(py)
+        }
+        Value::Object(entries) => {
+            let dict = PyDict::new_bound(py);
+            for (key, item) in entries {
+                dict.set_item(key, json_value_to_py(py, item)?)?;
+            }
+            dict.into_py(py)
+        }
+    })
+}
+
+/// Downloads many article pages concurrently and extracts their Open Graph
+/// images.
+///
+/// Returns a dict mapping each URL to the same dict `extract_og_image_html`
+/// returns, or to an error string when the page could not be fetched or its
+/// `Content-Type` is not HTML. Accepts `timeout_ms` and `client` like
+/// `fetch_feeds_parallel`.
+#[pyfunction(signature = (urls, max_concurrent=None, timeout_ms=None, client=None))]
+fn fetch_og_images_parallel<'py>(
+    py: Python<'py>,
+    urls: Vec<String>,
+    max_concurrent: Option<usize>,
+    timeout_ms: Option<u64>,
+    client: Option<PyRef<'py, FeedClient>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let runtime = shared_runtime()?;
+    let limit = max_concurrent.unwrap_or(32).max(1);
+    let request_timeout = request_timeout(timeout_ms, client.as_deref());
+    let options = ParseOptions {
+        client: client.map(|handle| handle.client()),
+        ..Default::default()
+    };
+    let extractions: Vec<(String, Result<OgImageExtraction, String>)> = py.allow_threads(|| {
+        runtime
+            .block_on(fetch_html_pages(urls, limit, request_timeout, &options))
+            .into_par_iter()
+            .map(|(url, page)| (url, page.map(|html| extract_og_image_from_html(&html))))
+            .collect()
+    });
+
+    let dict = PyDict::new_bound(py);
+    for (url, extraction) in extractions {
+        match extraction {
+            Ok(result) => dict.set_item(url, og_image_to_pydict(py, result)?)?,
+            Err(message) => dict.set_item(url, message)?,
+        }
+    }
+    Ok(dict)
+}
+
+/// Fetches an HTML page and lists the feeds it advertises through
+/// autodiscovery `<link rel="alternate">` tags.
+///
+/// Returns a list of dicts with `href` (resolved against the page URL),
+/// `title`, and `type` (`application/rss+xml`, `application/atom+xml`, or
+/// `application/feed+json`), empty when the page declares none. Raises
+/// `RuntimeError` when the page cannot be fetched or is not HTML. Accepts
+/// `timeout_ms` and `client` like `fetch_feeds_parallel`.
+#[pyfunction(signature = (url, timeout_ms=None, client=None))]
+fn discover_feeds<'py>(
+    py: Python<'py>,
+    url: String,
+    timeout_ms: Option<u64>,
+    client: Option<PyRef<'py, FeedClient>>,
+) -> PyResult<Bound<'py, pyo3::types::PyList>> {
+    let runtime = shared_runtime()?;
+    let request_timeout = request_timeout(timeout_ms, client.as_deref());
+    let options = ParseOptions {
+        client: client.map(|handle| handle.client()),
+        ..Default::default()
+    };
+    let page_url = url.trim().to_string();
+    let page = py.allow_threads(|| {
+        runtime
+            .block_on(fetch_html_pages(
+                vec![page_url.clone()],
+                1,
+                request_timeout,
+                &options,
+            ))
+            .into_iter()
+            .next()
+            .map(|(_, page)| page)
+            .unwrap_or_else(|| Err("No response".to_string()))
+            .map(|html| discover_feed_links(&html, &page_url))
+    });
+    let feeds = page.map_err(|message| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+            "Failed to fetch {page_url}: {message}"
+        ))
+    })?;
+
+    let list = pyo3::types::PyList::empty_bound(py);
+    for feed in feeds {
+        let item = PyDict::new_bound(py);
+        item.set_item("href", feed.href)?;
+        item.set_item("title", feed.title)?;
+        item.set_item("type", feed.feed_type)?;
+        list.append(item)?;
+    }
+    Ok(list)
+}
+
+/// Fetches a single feed URL and checks it without parsing articles, for
+/// quick feedback when a source is added.
+///
+/// Returns a dict with `ok`, `feed_title`, `article_count`, `feed_type`
+/// (`"rss"`, `"atom"`, `"json"`, `"html"` for a web page, or `None`), and
+/// `error`, which explains a fetch failure or unparseable body. Never raises
+/// for a bad URL. Accepts `timeout_ms` and `client` like
+/// `fetch_feeds_parallel`.
+#[pyfunction(signature = (url, timeout_ms=None, client=None))]
+fn validate_feed<'py>(
+    py: Python<'py>,
+    url: String,
+    timeout_ms: Option<u64>,
+    client: Option<PyRef<'py, FeedClient>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let runtime = shared_runtime()?;
+    let request_timeout = request_timeout(timeout_ms, client.as_deref());
+    let options = ParseOptions {
+        client: client.map(|handle| handle.client()),
+        ..Default::default()
+    };
+    let source_requests = ensure_source_requests(vec![(String::new(), vec![url])]);
+    let validation = py.allow_threads(|| {
+        runtime
+            .block_on(fetch_all(source_requests, 1, request_timeout, &options))
+            .first()
+            .map(validate_fetch_result)
+            .unwrap_or_else(|| FeedValidation {
+                error: Some("Empty URL".to_string()),
+                ..Default::default()
+            })
+    });
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("ok", validation.ok)?;
+    dict.set_item("feed_title", validation.feed_title)?;
+    dict.set_item("article_count", validation.article_count)?;
+    dict.set_item("feed_type", validation.feed_type)?;
+    dict.set_item("error", validation.error)?;
+    Ok(dict)
+}
+
+/// Reads the feed subscriptions from an OPML export.
+///
+/// Returns a flat list of dicts with `title`, `xml_url`, `html_url`, and
+/// `category`, in document order. `category` joins the labels of the folder
+/// outlines a feed is nested in with `/` (e.g. `"News/Europe"`), falling
+/// back to the outline's own `category` attribute. Outlines without a valid
+/// `http(s)` `xmlUrl` are skipped.
+#[pyfunction]
+fn parse_opml<'py>(py: Python<'py>, xml: &str) -> PyResult<Bound<'py, pyo3::types::PyList>> {
+    let feeds = py.allow_threads(|| parse_opml_feeds(xml));
+    let list = pyo3::types::PyList::empty_bound(py);
+    for feed in feeds {
+        let item = PyDict::new_bound(py);
+        item.set_item("title", feed.title)?;
+        item.set_item("xml_url", feed.xml_url)?;
+        item.set_item("html_url", feed.html_url)?;
+        item.set_item("category", feed.category)?;
+        list.append(item)?;
+    }
+    Ok(list)
+}
+
+/// Writes an OPML 2.0 document for `(title, xml_url, category)` feeds, the
+/// counterpart of `parse_opml`.
+///
+/// Categories become nested folder outlines split on `/`, so
+/// `"News/Europe"` nests a `Europe` folder inside `News`; feeds with no
+/// category sit at the top level. Titles and URLs are XML-escaped, and
+/// `title` names the document in its `<head>`.
+#[pyfunction(signature = (sources, title=None))]
+fn generate_opml(
+    py: Python<'_>,
+    sources: Vec<(String, String, Option<String>)>,
+    title: Option<String>,
+) -> String {
+    let title = title.unwrap_or_else(|| "Subscriptions".to_string());
+    py.allow_threads(|| generate_opml_document(&title, &sources))
+}
+
+/// Picks a site's icon URL from an HTML page.
+///
+/// Looks at `<link rel="icon">`, `rel="shortcut icon"`, and
+/// `rel="apple-touch-icon"` tags and returns the one with the largest
+/// declared `sizes`, resolved against `base_url` (the page address). Falls
+/// back to `/favicon.ico` on the page's host when no icon tag exists, and
+/// returns `None` only when `base_url` is not a valid URL either.
+#[pyfunction]
+fn discover_favicon_html(py: Python<'_>, html: String, base_url: String) -> Option<String> {
+    py.allow_threads(|| discover_favicon(&html, &base_url))
+}
+
 /// Registers all functions, constants, and metadata on the `rss_parser_rust`
 /// Python module during import.
 #[pymodule]
 fn rss_parser_rust(py: Python<'_>, module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_function(wrap_pyfunction!(parse_feeds_parallel, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_feeds_parallel_json, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_feed_bodies, module)?)?;
+    module.add_function(wrap_pyfunction!(fetch_feeds_parallel, module)?)?;
     module.add_function(wrap_pyfunction!(extract_article_html, module)?)?;
+    module.add_function(wrap_pyfunction!(extract_articles_batch, module)?)?;
     module.add_function(wrap_pyfunction!(extract_og_image_html, module)?)?;
+    module.add_function(wrap_pyfunction!(extract_json_ld_html, module)?)?;
+    module.add_function(wrap_pyfunction!(discover_favicon_html, module)?)?;
+    module.add_function(wrap_pyfunction!(fetch_og_images_parallel, module)?)?;
+    module.add_function(wrap_pyfunction!(discover_feeds, module)?)?;
+    module.add_function(wrap_pyfunction!(validate_feed, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_opml, module)?)?;
+    module.add_function(wrap_pyfunction!(generate_opml, module)?)?;
+    module.add_function(wrap_pyfunction!(merge_results, module)?)?;
+    module.add_class::<IngestProgress>()?;
+    module.add_class::<FeedClient>()?;
+    module.add_class::<CancelToken>()?;
+    module.add_class::<FeedCache>()?;
+    module.add_function(wrap_pyfunction!(articles_to_csv, module)?)?;
     module.add_function(wrap_pyfunction!(minhash_duplicate_pairs, module)?)?;
     module.add_function(wrap_pyfunction!(deduplicate_article_groups, module)?)?;
     module.add_function(wrap_pyfunction!(text_similarity, module)?)?;
@@ -0,0 +1,512 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{DateTime, FixedOffset};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString};
+
+const STATUS_RANK: &[&str] = &["success", "warning", "error"];
+
+fn status_rank(status: &str) -> usize {
+    STATUS_RANK
+        .iter()
+        .position(|candidate| *candidate == status)
+        .unwrap_or(0)
+}
+
+/// Chooses which articles survive a merge and in what order.
+///
+/// Each input is an article's dedupe key (guid, else link) and its parsed
+/// publish time. Returns the indices of the first occurrence of every key,
+/// newest first; ties and undated articles keep their input order, with
+/// undated articles last. Articles with an empty key are never deduplicated.
+pub fn merged_article_order(keys: &[(String, Option<DateTime<FixedOffset>>)]) -> Vec<usize> {
+    let mut seen = HashSet::new();
+    let mut kept: Vec<usize> = keys
+        .iter()
+        .enumerate()
+        .filter(|(_, (key, _))| key.is_empty() || seen.insert(key.as_str()))
+        .map(|(index, _)| index)
+        .collect();
+    kept.sort_by_key(|&index| Reverse(keys[index].1));
+    kept
+}
+
+fn article_merge_key(
+    article: &Bound<'_, PyDict>,
+) -> PyResult<(String, Option<DateTime<FixedOffset>>)> {
+    let text_field = |name: &str| -> PyResult<Option<String>> {
+        match article.get_item(name)? {
+            Some(value) if !value.is_none() => Ok(Some(value.extract::<String>()?)),
+            _ => Ok(None),
+        }
+    };
+
+    let key = text_field("guid")?
+        .filter(|guid| !guid.trim().is_empty())
+        .or(text_field("link")?)
+        .unwrap_or_default();
+    let published =
+        text_field("published")?.and_then(|value| DateTime::parse_from_rfc3339(&value).ok());
+    Ok((key, published))
+}
+
+/// How a numeric or boolean field present in more than one result combines.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Combine {
+    /// Add numbers; OR booleans.
+    Sum,
+    /// Keep the larger number; OR booleans.
+    Max,
+    /// Keep the smaller number; AND booleans.
+    Min,
+    /// Keep the first value that is not `None`.
+    First,
+    /// Keep the later RFC 3339 timestamp.
+    Latest,
+}
+
+/// The rule for a per-source stat field. Durations are wall-clock times of
+/// the same source, so the slowest run stands; the shortest `ttl_minutes`
+/// is the one to honor; a source only lacks dates if every run said so; and
+/// the latest `suggested_poll_after` is the most conservative. Fields not
+/// listed keep the first value.
+fn source_stat_rule(key: &str) -> Combine {
+    match key {
+        "article_count"
+        | "returned_count"
+        | "blocked_article_count"
+        | "filtered_out_count"
+        | "keyword_filtered_count"
+        | "merged_duplicate_count" => Combine::Sum,
+        "parse_timeout" | "fetch_duration_ms" | "parse_duration_ms" => Combine::Max,
+        "ttl_minutes" | "dates_synthesized" => Combine::Min,
+        "last_build_date" | "suggested_poll_after" => Combine::Latest,
+        "avg_publish_interval_minutes"
+        | "feed_icon_url"
+        | "feed_icon_data"
+        | "feed_title"
+        | "feed_description" => Combine::First,
+        _ => Combine::First,
+    }
+}
+
+/// The rule for a run metric. Counts and phase durations add up across
+/// batches; the concurrency limit and the slowest request take the maximum.
+/// Metrics not listed keep the first value.
+fn metric_rule(key: &str) -> Combine {
+    match key {
+        "total_duration_ms"
+        | "fetch_duration_ms"
+        | "parse_duration_ms"
+        | "articles_parsed"
+        | "fetch_attempts"
+        | "fetch_completed_within_2s"
+        | "fetch_completed_within_5s"
+        | "fetch_timed_out"
+        | "articles_blocked" => Combine::Sum,
+        "effective_concurrency" | "fetch_max_request_ms" | "cancelled" => Combine::Max,
+        _ => Combine::First,
+    }
+}
+
+fn combine_integers(rule: Combine, left: u128, right: u128) -> u128 {
+    match rule {
+        Combine::Sum => left.saturating_add(right),
+        Combine::Max | Combine::Latest => left.max(right),
+        Combine::Min => left.min(right),
+        Combine::First => left,
+    }
+}
+
+fn combine_floats(rule: Combine, left: f64, right: f64) -> f64 {
+    match rule {
+        Combine::Sum => left + right,
+        Combine::Max | Combine::Latest => left.max(right),
+        Combine::Min => left.min(right),
+        Combine::First => left,
+    }
+}
+
+fn combine_bools(rule: Combine, left: bool, right: bool) -> bool {
+    match rule {
+        Combine::Sum | Combine::Max | Combine::Latest => left || right,
+        Combine::Min => left && right,
+        Combine::First => left,
+    }
+}
+
+/// Combines two text fields: the later timestamp under [`Combine::Latest`],
+/// where one that does not parse as RFC 3339 loses, else the first value.
+fn combine_texts(rule: Combine, left: String, right: String) -> String {
+    if rule != Combine::Latest {
+        return left;
+    }
+    let parse = |value: &str| DateTime::parse_from_rfc3339(value).ok();
+    match (parse(&left), parse(&right)) {
+        (Some(earlier), Some(later)) if later > earlier => right,
+        (None, Some(_)) => right,
+        _ => left,
+    }
+}
+
+/// Adds per-key counts, such as `fetch_errors_by_kind`, into `total`.
+fn add_counts(total: &mut BTreeMap<String, u128>, incoming: BTreeMap<String, u128>) {
+    for (key, count) in incoming {
+        let slot = total.entry(key).or_default();
+        *slot = slot.saturating_add(count);
+    }
+}
+
+/// Appends the values of `incoming` not already in `merged`, keeping order.
+fn append_unique(merged: &mut Vec<String>, incoming: Vec<String>) {
+    for value in incoming {
+        if !merged.contains(&value) {
+            merged.push(value);
+        }
+    }
+}
+
+/// Folds one numeric, boolean, or text field into an accumulated dict by
+/// `rule`. A `None` is replaced by the incoming value; values of any other
+/// type keep the first one seen.
+fn merge_scalar(
+    target: &Bound<'_, PyDict>,
+    key: &str,
+    value: &Bound<'_, PyAny>,
+    rule: Combine,
+) -> PyResult<()> {
+    let Some(existing) = target.get_item(key)? else {
+        target.set_item(key, value)?;
+        return Ok(());
+    };
+
+    if value.is_none() {
+        return Ok(());
+    }
+    if existing.is_none() {
+        target.set_item(key, value)?;
+    } else if existing.is_instance_of::<PyBool>() && value.is_instance_of::<PyBool>() {
+        let merged = combine_bools(rule, existing.extract()?, value.extract()?);
+        target.set_item(key, merged)?;
+    } else if existing.is_instance_of::<PyInt>() && value.is_instance_of::<PyInt>() {
+        let merged = combine_integers(rule, existing.extract()?, value.extract()?);
+        target.set_item(key, merged)?;
+    } else if existing.is_instance_of::<PyFloat>() && value.is_instance_of::<PyFloat>() {
+        let merged = combine_floats(rule, existing.extract()?, value.extract()?);
+        target.set_item(key, merged)?;
+    } else if existing.is_instance_of::<PyString>() && value.is_instance_of::<PyString>() {
+        let merged = combine_texts(rule, existing.extract()?, value.extract()?);
+        target.set_item(key, merged)?;
+    }
+    Ok(())
+}
+
+fn merge_metric(target: &Bound<'_, PyDict>, key: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+    match key {
+        "fetch_errors_by_kind" => {
+            let mut total: BTreeMap<String, u128> = match target.get_item(key)? {
+                Some(existing) => existing.extract()?,
+                None => BTreeMap::new(),
+            };
+            add_counts(&mut total, value.extract()?);
+            target.set_item(key, total)
+        }
+        _ => merge_scalar(target, key, value, metric_rule(key)),
+    }
+}
+
+fn merge_source_stat(target: &Bound<'_, PyDict>, incoming: &Bound<'_, PyDict>) -> PyResult<()> {
+    for (key, value) in incoming.iter() {
+        let key: String = key.extract()?;
+        match key.as_str() {
+            "name" => {}
+            "status" => {
+                let current: String = target
+                    .get_item("status")?
+                    .map(|status| status.extract())
+                    .transpose()?
+                    .unwrap_or_default();
+                let candidate: String = value.extract()?;
+                if status_rank(&candidate) > status_rank(&current) {
+                    target.set_item("status", candidate)?;
+                }
+            }
+            "error_message" => {
+                let messages: Vec<String> = [target.get_item("error_message")?, Some(value)]
+                    .into_iter()
+                    .flatten()
+                    .filter(|message| !message.is_none())
+                    .map(|message| message.extract())
+                    .collect::<PyResult<_>>()?;
+                if !messages.is_empty() {
+                    target.set_item("error_message", messages.join("; "))?;
+                }
+            }
+            "sub_feeds" => {
+                let merged = PyList::empty_bound(target.py());
+                for list in [target.get_item("sub_feeds")?, Some(value)]
+                    .into_iter()
+                    .flatten()
+                {
+                    for sub in list.iter()? {
+                        merged.append(sub?)?;
+                    }
+                }
+                target.set_item("sub_feeds", merged)?;
+            }
+            "error_kinds" => {
+                let mut kinds: Vec<String> = match target.get_item(&key)? {
+                    Some(existing) => existing.extract()?,
+                    None => Vec::new(),
+                };
+                append_unique(&mut kinds, value.extract()?);
+                target.set_item(&key, kinds)?;
+            }
+            _ => merge_scalar(target, &key, &value, source_stat_rule(&key))?,
+        }
+    }
+    Ok(())
+}
+
+/// Deduplicates `articles` as [`merged_article_order`] does and returns the
+/// survivors newest first.
+fn merged_article_list<'py>(
+    py: Python<'py>,
+    articles: &[Bound<'py, PyDict>],
+) -> PyResult<Bound<'py, PyList>> {
+    let keys = articles
+        .iter()
+        .map(article_merge_key)
+        .collect::<PyResult<Vec<_>>>()?;
+    let merged = PyList::empty_bound(py);
+    for index in merged_article_order(&keys) {
+        merged.append(&articles[index])?;
+    }
+    Ok(merged)
+}
+
+/// Merges several `parse_feeds_parallel` results into one.
+///
+/// Articles are concatenated, deduplicated by `guid` (falling back to
+/// `link`) keeping the first occurrence, and re-sorted newest first; each
+/// source's `articles_by_source` list is merged the same way.
+/// `unchanged_sources` is the union of every result's list and `raw_xml`
+/// keeps the first document per URL. Source stats are combined per source
+/// name: counts add up, durations keep the slowest run, `ttl_minutes` the
+/// shortest, `last_build_date` and `suggested_poll_after` the latest,
+/// `error_kinds` and `sub_feeds` are concatenated (kinds without repeats),
+/// and other text fields keep the first value. Metric counts and phase
+/// durations are summed, `effective_concurrency` and `fetch_max_request_ms`
+/// take the maximum, `fetch_errors_by_kind` is summed per kind, and
+/// `articles_parsed` is reset to the merged article count.
+#[pyfunction]
+pub fn merge_results<'py>(
+    py: Python<'py>,
+    results: Vec<Bound<'py, PyDict>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let mut articles: Vec<Bound<'py, PyDict>> = Vec::new();
+    let mut articles_by_source: Option<BTreeMap<String, Vec<Bound<'py, PyDict>>>> = None;
+    let mut unchanged_sources: Option<Vec<String>> = None;
+    let mut raw_xml: Option<Bound<'py, PyDict>> = None;
+    let source_stats = PyDict::new_bound(py);
+    let metrics = PyDict::new_bound(py);
+
+    for result in &results {
+        if let Some(list) = result.get_item("articles")? {
+            for article in list.iter()? {
+                articles.push(article?.downcast_into::<PyDict>()?);
+            }
+        }
+
+        if let Some(grouped) = result.get_item("articles_by_source")? {
+            let merged = articles_by_source.get_or_insert_with(BTreeMap::new);
+            for (name, list) in grouped.downcast::<PyDict>()?.iter() {
+                let source_articles = merged.entry(name.extract()?).or_default();
+                for article in list.iter()? {
+                    source_articles.push(article?.downcast_into::<PyDict>()?);
+                }
+            }
+        }
+
+        if let Some(names) = result.get_item("unchanged_sources")? {
+            append_unique(
+                unchanged_sources.get_or_insert_with(Vec::new),
+                names.extract()?,
+            );
+        }
+
+        if let Some(documents) = result.get_item("raw_xml")? {
+            let merged = raw_xml.get_or_insert_with(|| PyDict::new_bound(py));
+            for (url, xml) in documents.downcast::<PyDict>()?.iter() {
+                if !merged.contains(&url)? {
+                    merged.set_item(url, xml)?;
+                }
+            }
+        }
+
+        if let Some(stats) = result.get_item("source_stats")? {
+            for (name, stat) in stats.downcast::<PyDict>()?.iter() {
+                let stat = stat.downcast_into::<PyDict>()?;
+                match source_stats.get_item(&name)? {
+                    Some(existing) => merge_source_stat(existing.downcast::<PyDict>()?, &stat)?,
+                    None => source_stats.set_item(&name, stat.copy()?)?,
+                }
+            }
+        }
+
+        if let Some(run_metrics) = result.get_item("metrics")? {
+            for (key, value) in run_metrics.downcast::<PyDict>()?.iter() {
+                merge_metric(&metrics, &key.extract::<String>()?, &value)?;
+            }
+        }
+    }
+
+    let merged_articles = merged_article_list(py, &articles)?;
+    metrics.set_item("articles_parsed", merged_articles.len())?;
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("articles", merged_articles)?;
+    if let Some(grouped) = articles_by_source {
+        let grouped_dict = PyDict::new_bound(py);
+        for (name, source_articles) in grouped {
+            grouped_dict.set_item(name, merged_article_list(py, &source_articles)?)?;
+        }
+        dict.set_item("articles_by_source", grouped_dict)?;
+    }
+    if let Some(names) = unchanged_sources {
+        dict.set_item("unchanged_sources", names)?;
+    }
+    if let Some(documents) = raw_xml {
+        dict.set_item("raw_xml", documents)?;
+    }
+    dict.set_item("source_stats", source_stats)?;
+    dict.set_item("metrics", metrics)?;
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use std::collections::BTreeMap;
+
+    use super::{
+        add_counts, append_unique, combine_bools, combine_integers, combine_texts,
+        merged_article_order, metric_rule, source_stat_rule, Combine,
+    };
+
+    #[test]
+    fn merge_order_dedupes_and_sorts_newest_first() {
+        let at = |value: &str| DateTime::parse_from_rfc3339(value).ok();
+        let keys = vec![
+            ("a".to_string(), at("2026-01-01T08:00:00Z")),
+            ("b".to_string(), at("2026-01-01T10:00:00Z")),
+            ("a".to_string(), at("2026-01-01T12:00:00Z")),
+            ("c".to_string(), None),
+            ("d".to_string(), at("2026-01-01T09:00:00+02:00")),
+        ];
+
+        assert_eq!(merged_article_order(&keys), vec![1, 0, 4, 3]);
+    }
+
+    #[test]
+    fn source_stats_and_metrics_merge_by_field_rule() {
+        let stat = |key: &str, left: u128, right: u128| {
+            combine_integers(source_stat_rule(key), left, right)
+        };
+        assert_eq!(stat("ttl_minutes", 60, 60), 60);
+        assert_eq!(stat("ttl_minutes", 60, 15), 15);
+        assert_eq!(stat("fetch_duration_ms", 120, 450), 450);
+        assert_eq!(stat("parse_duration_ms", 30, 10), 30);
+        assert_eq!(stat("article_count", 3, 4), 7);
+        assert_eq!(
+            source_stat_rule("avg_publish_interval_minutes"),
+            Combine::First
+        );
+        assert!(combine_bools(
+            source_stat_rule("parse_timeout"),
+            false,
+            true
+        ));
+        assert!(!combine_bools(
+            source_stat_rule("dates_synthesized"),
+            true,
+            false
+        ));
+
+        let metric =
+            |key: &str, left: u128, right: u128| combine_integers(metric_rule(key), left, right);
+        assert_eq!(metric("fetch_attempts", 5, 7), 12);
+        assert_eq!(metric("fetch_max_request_ms", 900, 400), 900);
+        assert_eq!(metric("effective_concurrency", 8, 32), 32);
+
+        let mut by_kind = BTreeMap::from([("dns".to_string(), 1), ("timeout".to_string(), 2)]);
+        add_counts(
+            &mut by_kind,
+            BTreeMap::from([("timeout".to_string(), 3), ("http_5xx".to_string(), 1)]),
+        );
+        assert_eq!(
+            by_kind,
+            BTreeMap::from([
+                ("dns".to_string(), 1),
+                ("http_5xx".to_string(), 1),
+                ("timeout".to_string(), 5),
+            ])
+        );
+
+        let mut kinds = vec!["timeout".to_string(), "dns".to_string()];
+        append_unique(&mut kinds, vec!["dns".to_string(), "parse".to_string()]);
+        assert_eq!(kinds, vec!["timeout", "dns", "parse"]);
+    }
+
+    #[test]
+    fn merging_two_results_keeps_the_latest_poll_time_and_unions_source_lists() {
+        let poll_after = |left: &str, right: &str| {
+            combine_texts(
+                source_stat_rule("suggested_poll_after"),
+                left.to_string(),
+                right.to_string(),
+            )
+        };
+        assert_eq!(
+            poll_after("2026-01-01T10:00:00+00:00", "2026-01-01T11:30:00+02:00"),
+            "2026-01-01T10:00:00+00:00"
+        );
+        assert_eq!(
+            poll_after("2026-01-01T10:00:00+00:00", "2026-01-01T11:00:00+00:00"),
+            "2026-01-01T11:00:00+00:00"
+        );
+        assert_eq!(
+            poll_after("soon", "2026-01-01T11:00:00+00:00"),
+            "2026-01-01T11:00:00+00:00"
+        );
+        assert_eq!(
+            combine_texts(
+                source_stat_rule("feed_title"),
+                "First".to_string(),
+                "Second".to_string()
+            ),
+            "First"
+        );
+        assert_eq!(source_stat_rule("some_future_field"), Combine::First);
+        assert_eq!(metric_rule("some_future_metric"), Combine::First);
+
+        let mut unchanged = vec!["Alpha".to_string(), "Beta".to_string()];
+        append_unique(
+            &mut unchanged,
+            vec!["Beta".to_string(), "Gamma".to_string()],
+        );
+        assert_eq!(unchanged, vec!["Alpha", "Beta", "Gamma"]);
+
+        // One source's articles_by_source lists from two results.
+        let at = |value: &str| DateTime::parse_from_rfc3339(value).ok();
+        let grouped = vec![
+            ("a".to_string(), at("2026-01-01T08:00:00Z")),
+            ("b".to_string(), at("2026-01-01T09:00:00Z")),
+            ("b".to_string(), at("2026-01-01T09:00:00Z")),
+            ("c".to_string(), at("2026-01-01T10:00:00Z")),
+        ];
+        assert_eq!(merged_article_order(&grouped), vec![3, 1, 0]);
+    }
+}
@@ -0,0 +1,351 @@
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// A feed subscription read from an OPML export.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OpmlFeed {
+    /// The outline's `text`, else its `title`, else the feed URL.
+    pub title: String,
+    /// The feed address from `xmlUrl`.
+    pub xml_url: String,
+    /// The site address from `htmlUrl`, when given.
+    pub html_url: Option<String>,
+    /// Labels of the enclosing folder outlines joined with `/`, else the
+    /// first entry of the outline's own `category` attribute.
+    pub category: Option<String>,
+}
+
+/// The attributes of a tag as `(name, value)` pairs, values entity-decoded
+/// and trimmed and blank ones dropped. HTML entities such as `&nbsp;` are
+/// decoded too, since exporters emit them, and unquoted or repeated
+/// attributes are tolerated.
+fn tag_attributes(tag: &BytesStart<'_>) -> Vec<(String, String)> {
+    tag.html_attributes()
+        .with_checks(false)
+        .flatten()
+        .filter_map(|attribute| {
+            let name = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+            let raw = String::from_utf8_lossy(&attribute.value);
+            let value = html_escape::decode_html_entities(&raw).trim().to_string();
+            (!value.is_empty()).then_some((name, value))
+        })
+        .collect()
+}
+
+/// Attribute value by case-insensitive name.
+fn attribute(attributes: &[(String, String)], name: &str) -> Option<String> {
+    attributes
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.clone())
+}
+
+fn is_outline(tag: &[u8]) -> bool {
+    tag.eq_ignore_ascii_case(b"outline")
+}
+
+/// Flattens the `<outline>` tree of an OPML document into its feeds, in
+/// document order.
+///
+/// Outlines with an `xmlUrl` are feeds; outlines without one are folders
+/// whose `text` (else `title`) labels the feeds nested inside them. Feeds
+/// whose `xmlUrl` is not an absolute `http(s)` URL are skipped. Mismatched
+/// end tags are tolerated, and a document that stops being well-formed
+/// yields the feeds read before that point.
+pub fn parse_opml_feeds(xml: &str) -> Vec<OpmlFeed> {
+    let mut feeds = Vec::new();
+    // One entry per open outline: its folder label, or `None` for an
+    // outline that is a feed itself or has no label.
+    let mut open: Vec<Option<String>> = Vec::new();
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().check_end_names = false;
+
+    loop {
+        let (tag, self_closing) = match reader.read_event() {
+            Ok(Event::Start(tag)) => (tag, false),
+            Ok(Event::Empty(tag)) => (tag, true),
+            Ok(Event::End(tag)) => {
+                if is_outline(tag.local_name().as_ref()) {
+                    open.pop();
+                }
+                continue;
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(_) => continue,
+        };
+        if !is_outline(tag.local_name().as_ref()) {
+            continue;
+        }
+        let attributes = tag_attributes(&tag);
+        let label = attribute(&attributes, "text").or_else(|| attribute(&attributes, "title"));
+
+        let Some(xml_url) = attribute(&attributes, "xmlUrl") else {
+            if !self_closing {
+                open.push(label);
+            }
+            continue;
+        };
+        if !self_closing {
+            open.push(None);
+        }
+        let is_web_url = url::Url::parse(&xml_url)
+            .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.has_host());
+        if !is_web_url {
+            continue;
+        }
+
+        let folders: Vec<&str> = open.iter().flatten().map(String::as_str).collect();
+        let category = if folders.is_empty() {
+            attribute(&attributes, "category").and_then(|categories| {
+                categories
+                    .split(',')
+                    .map(|category| category.trim().trim_matches('/').to_string())
+                    .find(|category| !category.is_empty())
+            })
+        } else {
+            Some(folders.join("/"))
+        };
+        feeds.push(OpmlFeed {
+            title: label.unwrap_or_else(|| xml_url.clone()),
+            html_url: attribute(&attributes, "htmlUrl"),
+            xml_url,
+            category,
+        });
+    }
+    feeds
+}
+
+/// One level of the outline tree [`generate_opml_document`] writes.
+enum OutlineNode<'a> {
+    Feed {
+        title: &'a str,
+        xml_url: &'a str,
+    },
+    Folder {
+        name: &'a str,
+        children: Vec<OutlineNode<'a>>,
+    },
+}
+
+/// Escapes text for a double-quoted XML attribute, dropping characters XML
+/// 1.0 cannot carry and encoding tabs and line breaks so they survive
+/// attribute normalization.
+fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' => escaped.push_str("&#9;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\u{FFFE}' | '\u{FFFF}' => {}
+            ch if ch.is_control() && ch < ' ' => {}
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn write_outlines(nodes: &[OutlineNode<'_>], depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    for node in nodes {
+        match node {
+            OutlineNode::Feed { title, xml_url } => {
+                let title = escape_attribute(title);
+                out.push_str(&format!(
+                    "{indent}<outline type=\"rss\" text=\"{title}\" title=\"{title}\" xmlUrl=\"{}\"/>\n",
+                    escape_attribute(xml_url)
+                ));
+            }
+            OutlineNode::Folder { name, children } => {
+                let name = escape_attribute(name);
+                out.push_str(&format!(
+                    "{indent}<outline text=\"{name}\" title=\"{name}\">\n"
+                ));
+                write_outlines(children, depth + 1, out);
+                out.push_str(&format!("{indent}</outline>\n"));
+            }
+        }
+    }
+}
+
+/// Writes an OPML 2.0 document listing `(title, xml_url, category)` feeds.
+///
+/// Categories become nested folder outlines, splitting on `/` the same way
+/// [`parse_opml_feeds`] joins them, so `"News/Europe"` puts the feed in a
+/// `Europe` folder inside `News`. Feeds without a category sit at the top
+/// level. Folders and feeds keep the order in which they first appear.
+pub fn generate_opml_document(
+    head_title: &str,
+    sources: &[(String, String, Option<String>)],
+) -> String {
+    let mut root: Vec<OutlineNode<'_>> = Vec::new();
+    for (title, xml_url, category) in sources {
+        let mut level = &mut root;
+        let folders = category
+            .as_deref()
+            .unwrap_or_default()
+            .split('/')
+            .map(str::trim)
+            .filter(|name| !name.is_empty());
+        for folder in folders {
+            let position = level.iter().position(
+                |node| matches!(node, OutlineNode::Folder { name, .. } if *name == folder),
+            );
+            let position = position.unwrap_or_else(|| {
+                level.push(OutlineNode::Folder {
+                    name: folder,
+                    children: Vec::new(),
+                });
+                level.len() - 1
+            });
+            let OutlineNode::Folder { children, .. } = &mut level[position] else {
+                unreachable!("position points at a folder");
+            };
+            level = children;
+        }
+        level.push(OutlineNode::Feed { title, xml_url });
+    }
+
+    let mut out =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">\n");
+    out.push_str(&format!(
+        "  <head>\n    <title>{}</title>\n  </head>\n  <body>\n",
+        html_escape::encode_text(head_title)
+    ));
+    write_outlines(&root, 2, &mut out);
+    out.push_str("  </body>\n</opml>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_opml_document, parse_opml_feeds, OpmlFeed};
+
+    #[test]
+    fn flattens_nested_outlines_with_folder_categories() {
+        let opml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<opml version="2.0">
+  <head><title>Subscriptions</title></head>
+  <body>
+    <outline text="News" title="News">
+      <outline type="rss" text="World &amp; Politics" xmlUrl="https://example.com/world.xml" htmlUrl="https://example.com/world"/>
+      <outline text="Europe">
+        <outline type="rss" title="Berlin Desk" xmlurl="https://example.de/feed"/>
+      </outline>
+      <!-- <outline text="Old" xmlUrl="https://old.example.com/rss"/> -->
+    </outline>
+    <outline type="rss" text="Tech" xmlUrl='https://tech.example.com/rss' category="/Technology/AI,/Science"/>
+    <outline text="Broken" xmlUrl="not a url"/>
+    <outline text="No feed here"/>
+  </body>
+</opml>"#;
+
+        let feeds = parse_opml_feeds(opml);
+
+        assert_eq!(
+            feeds,
+            vec![
+                OpmlFeed {
+                    title: "World & Politics".to_string(),
+                    xml_url: "https://example.com/world.xml".to_string(),
+                    html_url: Some("https://example.com/world".to_string()),
+                    category: Some("News".to_string()),
+                },
+                OpmlFeed {
+                    title: "Berlin Desk".to_string(),
+                    xml_url: "https://example.de/feed".to_string(),
+                    html_url: None,
+                    category: Some("News/Europe".to_string()),
+                },
+                OpmlFeed {
+                    title: "Tech".to_string(),
+                    xml_url: "https://tech.example.com/rss".to_string(),
+                    html_url: None,
+                    category: Some("Technology/AI".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_markup_characters_inside_attribute_values() {
+        let opml = r#"<opml version="2.0"><body>
+  <outline text="A > B" xmlUrl="https://example.com/a-b.xml"/>
+  <outline text="Caf&eacute;&nbsp;&lt;Daily&gt;" xmlUrl='https://example.com/cafe.xml?a=1&amp;b=2'/>
+  <!-- <outline text="Old > New" xmlUrl="https://old.example.com/rss"/> -->
+</body></opml>"#;
+
+        let feeds = parse_opml_feeds(opml);
+
+        let read: Vec<(&str, &str)> = feeds
+            .iter()
+            .map(|feed| (feed.title.as_str(), feed.xml_url.as_str()))
+            .collect();
+        assert_eq!(
+            read,
+            vec![
+                ("A > B", "https://example.com/a-b.xml"),
+                (
+                    "Caf\u{e9}\u{a0}<Daily>",
+                    "https://example.com/cafe.xml?a=1&b=2"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn generated_opml_round_trips_through_the_parser() {
+        let source = |title: &str, xml_url: &str, category: Option<&str>| {
+            (
+                title.to_string(),
+                xml_url.to_string(),
+                category.map(str::to_string),
+            )
+        };
+        let sources = vec![
+            source(
+                "World & \"Politics\"",
+                "https://example.com/world.xml?a=1&b=2",
+                Some("News"),
+            ),
+            source(
+                "Berlin <Desk>",
+                "https://example.de/feed",
+                Some("News/Europe"),
+            ),
+            source("Paris", "https://example.fr/rss", Some(" News / Europe ")),
+            source("Tech", "https://tech.example.com/rss", None),
+        ];
+
+        let opml = generate_opml_document("My feeds", &sources);
+
+        assert!(opml.contains("<opml version=\"2.0\">"));
+        assert!(opml.contains("text=\"World &amp; &quot;Politics&quot;\""));
+        let feeds = parse_opml_feeds(&opml);
+        let recovered: Vec<(String, String, Option<String>)> = feeds
+            .into_iter()
+            .map(|feed: OpmlFeed| (feed.title, feed.xml_url, feed.category))
+            .collect();
+        assert_eq!(
+            recovered,
+            vec![
+                source(
+                    "World & \"Politics\"",
+                    "https://example.com/world.xml?a=1&b=2",
+                    Some("News")
+                ),
+                source(
+                    "Berlin <Desk>",
+                    "https://example.de/feed",
+                    Some("News/Europe")
+                ),
+                source("Paris", "https://example.fr/rss", Some("News/Europe")),
+                source("Tech", "https://tech.example.com/rss", None),
+            ]
+        );
+    }
+}
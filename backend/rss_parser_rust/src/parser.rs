@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::time::{Duration, Instant};
 
 use feed_rs::model::Content;
@@ -6,10 +6,17 @@ use feed_rs::parser;
 use rayon::prelude::*;
 use regex::Regex;
 
-use crate::cleaner::clean_html;
-use crate::fetcher::fetch_all;
+use crate::cache::{ValidatorStore, ValidatorUpdate};
+use crate::cleaner::{
+    clean_html, clean_html_preserve_paragraphs, clean_html_to_markdown, clean_html_with_spacing,
+    sanitize_html, strip_decorative_chars, truncate_at_word_boundary,
+};
+use crate::fetcher::{feed_from_bytes, fetch_all_with_concurrency, fetch_icon_data_uris};
+use crate::html_extract::extract_json_ld_event;
 use crate::types::{
-    FetchResult, ParseResult, ParsedArticle, SourceRequest, SourceStats, SubFeedStat,
+    DescriptionFormat, DuplicateGuidPolicy, Enclosure, EventDetails, FeedValidation, FetchResult,
+    ParseOptions, ParseResult, ParsedArticle, PreviousSourceStat, RawFeed, SourceRequest,
+    SourceStats, SubFeedStat,
 };
 
 #[derive(Debug, Default)]
@@ -18,6 +25,7 @@ struct RssItemMetadata {
     link: Option<String>,
     authors: Vec<String>,
     author_urls: Vec<String>,
+    event: Option<EventDetails>,
 }
 
 fn push_unique_author(value: &str, seen: &mut HashSet<String>, authors: &mut Vec<String>) {
@@ -72,11 +80,15 @@ fn extract_entry_authors(entry: &feed_rs::model::Entry) -> (Vec<String>, Vec<Str
     let mut seen = HashSet::new();
 
     for person in &entry.authors {
+        // feed-rs names Atom authors without a <name> "unknown".
         let name = person.name.trim();
+        let name = if name == "unknown" { "" } else { name };
         if !name.is_empty() {
             for split in split_author_name(name) {
                 push_unique_author(&split, &mut seen, &mut authors);
             }
+        } else if let Some(email) = &person.email {
+            push_unique_author(email.trim(), &mut seen, &mut authors);
         }
         if let Some(uri) = &person.uri {
             let trimmed = uri.trim();
@@ -165,6 +177,14 @@ fn extract_rss_item_metadata(xml: &str) -> Vec<RssItemMetadata> {
         r#"(?is)<link[^>]*rel\s*=\s*["']author["'][^>]*href\s*=\s*["'](?P<plain>[^"']+)["'][^>]*>"#,
     )
     .expect("valid link rel=author regex");
+    let ev_start_re = Regex::new(r#"(?is)<ev:startdate[^>]*>(?P<plain>.*?)</ev:startdate>"#)
+        .expect("valid ev:startdate regex");
+    let ev_end_re = Regex::new(r#"(?is)<ev:enddate[^>]*>(?P<plain>.*?)</ev:enddate>"#)
+        .expect("valid ev:enddate regex");
+    let ev_location_re = Regex::new(
+        r#"(?is)<ev:location[^>]*><!\[CDATA\[(?P<cdata>.*?)\]\]></ev:location>|<ev:location[^>]*>(?P<plain>.*?)</ev:location>"#,
+    )
+    .expect("valid ev:location regex");
 
     item_re
         .find_iter(xml)
@@ -275,16 +295,80 @@ fn extract_rss_item_metadata(xml: &str) -> Vec<RssItemMetadata> {
                 }
             }
 
+            // ev: namespace (RSS 1.0 event module)
+            let title = extract_tag_value(item_xml, &title_re);
+            let event = if item_xml.contains("<ev:") {
+                let details = EventDetails {
+                    name: title.clone(),
+                    start: extract_tag_value(item_xml, &ev_start_re),
+                    end: extract_tag_value(item_xml, &ev_end_re),
+                    location: extract_tag_value(item_xml, &ev_location_re),
+                };
+                (details.start.is_some() || details.end.is_some() || details.location.is_some())
+                    .then_some(details)
+            } else {
+                None
+            };
+
             RssItemMetadata {
-                title: extract_tag_value(item_xml, &title_re),
+                title,
                 link: extract_tag_value(item_xml, &link_re),
                 authors,
                 author_urls,
+                event,
             }
         })
         .collect()
 }
 
+/// Whether a response body is JSON (e.g. JSON Feed) rather than XML.
+fn is_json_document(body: &str) -> bool {
+    body.trim_start_matches('\u{FEFF}')
+        .trim_start()
+        .starts_with('{')
+}
+
+/// The part of a response body handed to the feed parser: JSON Feed bodies
+/// as-is, XML trimmed to its closing root tag.
+fn feed_document(body: &str) -> &str {
+    if is_json_document(body) {
+        body.trim_start_matches('\u{FEFF}').trim()
+    } else {
+        trim_to_feed_document(body)
+    }
+}
+
+/// Maps JSON Feed item ids to their `image` (or `banner_image`), which the
+/// feed parser does not surface as media.
+fn extract_json_feed_images(body: &str) -> HashMap<String, String> {
+    let Ok(feed) = serde_json::from_str::<serde_json::Value>(body) else {
+        return HashMap::new();
+    };
+    let Some(items) = feed.get("items").and_then(|items| items.as_array()) else {
+        return HashMap::new();
+    };
+
+    items
+        .iter()
+        .filter_map(|item| {
+            let id = item
+                .get("id")
+                .and_then(|id| match id {
+                    serde_json::Value::String(id) => Some(id.clone()),
+                    serde_json::Value::Number(id) => Some(id.to_string()),
+                    _ => None,
+                })
+                .or_else(|| item.get("url")?.as_str().map(str::to_string))?;
+            let image = ["image", "banner_image"]
+                .iter()
+                .filter_map(|key| item.get(*key)?.as_str())
+                .map(str::trim)
+                .find(|image| !image.is_empty())?;
+            Some((id.trim().to_string(), image.to_string()))
+        })
+        .collect()
+}
+
 fn trim_to_feed_document(xml: &str) -> &str {
     for closing_tag in ["</rss>", "</feed>"] {
         if let Some(end) = xml.rfind(closing_tag) {
@@ -307,40 +391,109 @@ fn trim_to_feed_document(xml: &str) -> &str {
     xml
 }
 
+fn find_rss_item_metadata<'a>(
+    item_metadata: &'a [RssItemMetadata],
+    link: &str,
+    title: &str,
+    index: usize,
+) -> Option<&'a RssItemMetadata> {
+    item_metadata
+        .iter()
+        .find(|item| item.link.as_deref() == Some(link) || item.title.as_deref() == Some(title))
+        .or_else(|| item_metadata.get(index))
+}
+
 fn find_rss_item_authors(
     item_metadata: &[RssItemMetadata],
     link: &str,
     title: &str,
     index: usize,
 ) -> (Vec<String>, Vec<String>) {
-    if let Some(metadata) = item_metadata
-        .iter()
-        .find(|item| item.link.as_deref() == Some(link) || item.title.as_deref() == Some(title))
+    find_rss_item_metadata(item_metadata, link, title, index)
+        .map(|item| (item.authors.clone(), item.author_urls.clone()))
+        .unwrap_or_default()
+}
+
+fn extract_entry_event(
+    entry: &feed_rs::model::Entry,
+    item_metadata: &[RssItemMetadata],
+    link: &str,
+    title: &str,
+    index: usize,
+) -> Option<EventDetails> {
+    if let Some(event) = find_rss_item_metadata(item_metadata, link, title, index)
+        .and_then(|item| item.event.clone())
     {
-        return (metadata.authors.clone(), metadata.author_urls.clone());
+        return Some(event);
     }
 
-    item_metadata
-        .get(index)
-        .map(|item| (item.authors.clone(), item.author_urls.clone()))
-        .unwrap_or_default()
+    let body = entry.content.as_ref()?.body.as_deref()?;
+    if !body.contains("application/ld+json") {
+        return None;
+    }
+    extract_json_ld_event(&scraper::Html::parse_fragment(body))
+}
+
+/// Records a run's validators in the feed cache. A fresh response only
+/// replaces the cached validators once its sub-feed parsed in full; after a
+/// parse error, or a parse the deadline skipped or cut short, the old ones
+/// stay, so the next run gets the whole feed again instead of a `304` for
+/// entries never read.
+fn record_parsed_validators(
+    cache: &ValidatorStore,
+    updates: Vec<ValidatorUpdate>,
+    source_stats: &BTreeMap<String, SourceStats>,
+) {
+    let parsed: HashSet<&str> = source_stats
+        .values()
+        .flat_map(|stat| stat.sub_feeds.iter().flatten())
+        .filter(|sub| sub.status == "success")
+        .map(|sub| sub.url.as_str())
+        .collect();
+    cache.record(updates.into_iter().filter(|update| match update {
+        ValidatorUpdate::Fresh { url, .. } => parsed.contains(url.as_str()),
+        ValidatorUpdate::NotModified { .. } => true,
+    }));
 }
 
 /// Fetches all requested sources concurrently, then parses the returned
 /// RSS/Atom XML into [`ParsedArticle`] entries with per-source statistics
 /// and timing metrics.
 ///
-/// Concurrency is bounded by `max_concurrent` using a semaphore.
+/// Concurrency is bounded by `max_concurrent` using a semaphore. URLs with
+/// a body in [`ParseOptions::feed_bodies`] are parsed from it without a
+/// request, so a run made only of supplied bodies never touches the network.
 pub async fn parse_sources(
     sources: Vec<SourceRequest>,
     max_concurrent: usize,
     request_timeout: Duration,
+    options: &ParseOptions,
 ) -> ParseResult {
     let start = Instant::now();
 
+    let (supplied, to_fetch) = take_supplied_bodies(&sources, options);
     let fetch_start = Instant::now();
-    let fetch_results = fetch_all(sources.clone(), max_concurrent, request_timeout).await;
-    let fetch_duration = fetch_start.elapsed();
+    let (mut fetch_results, effective_concurrency) =
+        fetch_all_with_concurrency(to_fetch, max_concurrent, request_timeout, options).await;
+    // Supplied bodies are not fetches: a run made only of them reports no
+    // fetch time, and the fetch metrics below count requests actually made.
+    let fetch_duration = if fetch_results.is_empty() {
+        Duration::ZERO
+    } else {
+        fetch_start.elapsed()
+    };
+    let validator_updates: Vec<ValidatorUpdate> = if options.feed_cache.is_some() {
+        fetch_results
+            .iter()
+            .filter_map(ValidatorUpdate::from_result)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let cancelled = options
+        .cancel
+        .as_ref()
+        .is_some_and(|token| token.is_cancelled());
     let fetch_attempts = fetch_results.len();
     let fetch_completed_within_2s = fetch_results
         .iter()
@@ -354,15 +507,54 @@ pub async fn parse_sources(
         .iter()
         .filter(|result| matches!(result, FetchResult::Error(err) if err.timed_out))
         .count();
+    let mut fetch_errors_by_kind = BTreeMap::new();
+    for result in &fetch_results {
+        if let FetchResult::Error(err) = result {
+            *fetch_errors_by_kind
+                .entry(err.error_kind.clone())
+                .or_insert(0) += 1;
+        }
+    }
     let fetch_max_request_ms = fetch_results
         .iter()
         .map(fetch_result_duration_ms)
         .max()
         .unwrap_or_default();
+    fetch_results.extend(supplied);
+
+    let raw_xml = (!options.raw_xml_sources.is_empty())
+        .then(|| raw_xml_by_url(&fetch_results, &options.raw_xml_sources));
 
     let parse_start = Instant::now();
-    let (articles, source_stats) = parse_results(fetch_results, sources);
+    let (articles, mut source_stats) = parse_results(fetch_results, sources, options);
+    if let Some(cache) = &options.feed_cache {
+        record_parsed_validators(cache, validator_updates, &source_stats);
+    }
+    let articles_by_source = options
+        .group_by_source
+        .then(|| group_articles_by_source(&articles));
     let parse_duration = parse_start.elapsed();
+    if options.inline_icons && !cancelled {
+        let icon_urls: Vec<String> = source_stats
+            .values()
+            .filter_map(|stat| stat.feed_icon_url.clone())
+            .collect();
+        let icons = fetch_icon_data_uris(icon_urls, max_concurrent, request_timeout, options).await;
+        for stat in source_stats.values_mut() {
+            stat.feed_icon_data = stat
+                .feed_icon_url
+                .as_ref()
+                .and_then(|url| icons.get(url).cloned());
+        }
+    }
+    let articles_blocked = source_stats
+        .values()
+        .map(|stat| stat.blocked_article_count)
+        .sum();
+    let unchanged_sources = options
+        .previous_source_stats
+        .as_ref()
+        .map(|previous| remove_unchanged_sources(&mut source_stats, previous));
 
     ParseResult {
         metrics: crate::types::RustMetrics {
@@ -374,53 +566,268 @@ pub async fn parse_sources(
             fetch_completed_within_2s,
             fetch_completed_within_5s,
             fetch_timed_out,
+            fetch_errors_by_kind,
+            effective_concurrency,
             fetch_max_request_ms,
+            articles_blocked,
+            cancelled,
         },
         articles,
         source_stats,
+        articles_by_source,
+        unchanged_sources,
+        raw_xml,
+    }
+}
+
+/// Splits off the feed URLs whose bodies the caller supplied, decoding each
+/// as if it had been fetched, and returns them with the sources still left
+/// to fetch. Sources with nothing left to fetch are dropped from the latter.
+fn take_supplied_bodies(
+    sources: &[SourceRequest],
+    options: &ParseOptions,
+) -> (Vec<FetchResult>, Vec<SourceRequest>) {
+    if options.feed_bodies.is_empty() {
+        return (Vec::new(), sources.to_vec());
+    }
+    let mut supplied = Vec::new();
+    let mut to_fetch = Vec::new();
+    for source in sources {
+        let mut urls = Vec::new();
+        for url in &source.urls {
+            match options.feed_bodies.get(url) {
+                Some(bytes) => supplied.push(feed_from_bytes(
+                    &source.name,
+                    url,
+                    bytes,
+                    options.unzip_archives,
+                )),
+                None => urls.push(url.clone()),
+            }
+        }
+        if !urls.is_empty() {
+            to_fetch.push(SourceRequest {
+                name: source.name.clone(),
+                urls,
+            });
+        }
+    }
+    (supplied, to_fetch)
+}
+
+/// Copies the fetched bodies of the named sources, keyed by feed URL, so only
+/// opted-in feeds outlive parsing.
+fn raw_xml_by_url(results: &[FetchResult], sources: &HashSet<String>) -> BTreeMap<String, String> {
+    results
+        .iter()
+        .filter_map(|result| match result {
+            FetchResult::Success(raw) if sources.contains(&raw.source_name) => {
+                Some((raw.url.clone(), raw.xml.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Drops stats whose status and article count match the previous run and
+/// returns the dropped source names, sorted.
+fn remove_unchanged_sources(
+    stats: &mut BTreeMap<String, SourceStats>,
+    previous: &HashMap<String, PreviousSourceStat>,
+) -> Vec<String> {
+    let unchanged: Vec<String> = stats
+        .iter()
+        .filter(|(name, stat)| {
+            previous.get(*name).is_some_and(|prior| {
+                prior.status == stat.status && prior.article_count == stat.article_count
+            })
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+    for name in &unchanged {
+        stats.remove(name);
+    }
+    unchanged
+}
+
+fn published_timestamp(article: &ParsedArticle) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    chrono::DateTime::parse_from_rfc3339(&article.published).ok()
+}
+
+/// Digits used for the zero-padded millisecond prefix of a sort key, wide
+/// enough for any date chrono can represent after the epoch.
+const SORT_KEY_MS_WIDTH: usize = 15;
+
+/// Builds [`ParsedArticle::sort_key`] from the publish time and the guid,
+/// falling back to the link. Pre-epoch or unparseable dates sort first, as
+/// do undated entries: their `published` is the parse time, which would
+/// give the same entry a new key on every run.
+fn article_sort_key(article: &ParsedArticle) -> String {
+    let millis = published_timestamp(article)
+        .filter(|_| !article.published_synthesized)
+        .map(|published| published.timestamp_millis().max(0))
+        .unwrap_or_default();
+    let id = article.guid.as_deref().unwrap_or(article.link.as_str());
+    format!("{millis:0width$}:{id}", width = SORT_KEY_MS_WIDTH)
+}
+
+/// Hex digits kept from the MD5 digest for [`ParsedArticle::content_hash`].
+const CONTENT_HASH_HEX_LEN: usize = 16;
+
+/// Builds [`ParsedArticle::content_hash`]. Runs of whitespace collapse to one
+/// space so reflowed markup does not count as an edit.
+fn content_hash(article: &ParsedArticle) -> String {
+    let normalized = [&article.title, &article.link, &article.description]
+        .iter()
+        .map(|field| field.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join("\u{1f}");
+    let mut hash = format!("{:x}", md5::compute(normalized.as_bytes()));
+    hash.truncate(CONTENT_HASH_HEX_LEN);
+    hash
+}
+
+/// Reading speed behind [`ParsedArticle::reading_time_minutes`].
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Counts whitespace-separated words and estimates whole reading minutes,
+/// rounding up so any text takes at least a minute.
+fn reading_stats(text: &str) -> (usize, u32) {
+    let word_count = text.split_whitespace().count();
+    let minutes = word_count.div_ceil(WORDS_PER_MINUTE);
+    (word_count, u32::try_from(minutes).unwrap_or(u32::MAX))
+}
+
+fn group_articles_by_source(articles: &[ParsedArticle]) -> BTreeMap<String, Vec<ParsedArticle>> {
+    let mut grouped: BTreeMap<String, Vec<ParsedArticle>> = BTreeMap::new();
+    for article in articles {
+        grouped
+            .entry(article.source.clone())
+            .or_default()
+            .push(article.clone());
+    }
+    for source_articles in grouped.values_mut() {
+        source_articles.sort_by_key(|article| std::cmp::Reverse(published_timestamp(article)));
     }
+    grouped
+}
+
+/// Orders articles newest first by their parsed `published` time. Undated
+/// articles, whose `published` is only the parse time, sort after every
+/// dated one, as do dates that do not parse; ties keep their order.
+fn sort_articles_newest_first(articles: &mut [ParsedArticle]) {
+    articles.sort_by_key(|article| {
+        let published = published_timestamp(article).filter(|_| !article.published_synthesized);
+        std::cmp::Reverse(published)
+    });
 }
 
 fn fetch_result_duration_ms(result: &FetchResult) -> u128 {
     match result {
         FetchResult::Success(raw) => raw.duration_ms,
         FetchResult::Error(err) => err.duration_ms,
+        FetchResult::NotModified(unchanged) => unchanged.duration_ms,
     }
 }
 
 fn parse_results(
     fetch_results: Vec<FetchResult>,
     original_sources: Vec<SourceRequest>,
-) -> (Vec<ParsedArticle>, HashMap<String, SourceStats>) {
+    options: &ParseOptions,
+) -> (Vec<ParsedArticle>, BTreeMap<String, SourceStats>) {
     let mut grouped: HashMap<String, Vec<FetchResult>> = HashMap::new();
     for result in fetch_results {
-        match &result {
-            FetchResult::Success(raw) => {
-                grouped
-                    .entry(raw.source_name.clone())
-                    .or_default()
-                    .push(result);
-            }
-            FetchResult::Error(err) => {
-                grouped
-                    .entry(err.source_name.clone())
-                    .or_default()
-                    .push(result);
-            }
-        }
+        let source_name = match &result {
+            FetchResult::Success(raw) => raw.source_name.clone(),
+            FetchResult::Error(err) => err.source_name.clone(),
+            FetchResult::NotModified(unchanged) => unchanged.source_name.clone(),
+        };
+        grouped.entry(source_name).or_default().push(result);
+    }
+
+    // Parse sub-feeds in request order so "first occurrence" is stable and
+    // `sub_feeds` lists them the same way every run, whatever order the
+    // fetches finished in.
+    for (source_name, results) in grouped.iter_mut() {
+        let url_order: HashMap<&str, usize> = original_sources
+            .iter()
+            .filter(|source| &source.name == source_name)
+            .flat_map(|source| source.urls.iter())
+            .enumerate()
+            .map(|(index, url)| (url.as_str(), index))
+            .collect();
+        results.sort_by_key(|result| {
+            url_order
+                .get(fetch_result_url(result))
+                .copied()
+                .unwrap_or(usize::MAX)
+        });
     }
 
-    let articles_stats: Vec<_> = grouped
+    // Every sub-feed is its own unit of parallel work, so a source with
+    // dozens of feeds does not parse them one at a time; results come back
+    // in order and are regrouped by source before merging.
+    let deadline = options.parse_deadline.map(|budget| Instant::now() + budget);
+    let groups: Vec<(&String, &Vec<FetchResult>)> = grouped.iter().collect();
+    let sub_feeds: Vec<(&str, &FetchResult)> = groups
+        .iter()
+        .flat_map(|(source_name, results)| {
+            results
+                .iter()
+                .map(move |result| (source_name.as_str(), result))
+        })
+        .collect();
+    let mut parsed = sub_feeds
         .par_iter()
-        .map(|(source_name, results)| parse_source_group(source_name, results))
+        .map(|(source_name, result)| parse_sub_feed(source_name, result, options, deadline))
+        .collect::<Vec<_>>()
+        .into_iter();
+    let per_source: Vec<_> = groups
+        .into_iter()
+        .map(|(source_name, results)| {
+            let source_parsed: Vec<SubFeedParse> = parsed.by_ref().take(results.len()).collect();
+            (source_name, results, source_parsed)
+        })
+        .collect();
+    let mut articles_stats: Vec<_> = per_source
+        .into_par_iter()
+        .map(|(source_name, results, source_parsed)| {
+            merge_sub_feeds(source_name, results, source_parsed, options)
+        })
+        .collect();
+
+    // Sources merge in request order, so articles come out in the same
+    // order every run and the earliest source wins cross-source dedupe.
+    let source_order: HashMap<&str, usize> = original_sources
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(index, source)| (source.name.as_str(), index))
         .collect();
+    let position = |stat: &SourceStats| {
+        source_order
+            .get(stat.name.as_str())
+            .copied()
+            .unwrap_or(usize::MAX)
+    };
+    articles_stats.sort_by(|(_, a), (_, b)| {
+        position(a)
+            .cmp(&position(b))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    if options.dedupe {
+        dedupe_articles(&mut articles_stats, &tracking_params(options));
+    }
 
     let mut articles = Vec::new();
-    let mut stats = HashMap::new();
+    let mut stats = BTreeMap::new();
     for (mut source_articles, stat) in articles_stats {
         articles.append(&mut source_articles);
         stats.insert(stat.name.clone(), stat);
     }
+    if options.sort_by_date {
+        sort_articles_newest_first(&mut articles);
+    }
 
     // Ensure sources without fetch attempt still have stats
     for source in original_sources {
@@ -432,167 +839,1155 @@ fn parse_results(
                 article_count: 0,
                 error_message: Some("No fetch attempts".to_string()),
                 sub_feeds: None,
+                ..Default::default()
             });
     }
 
     (articles, stats)
 }
 
-fn parse_source_group(
+/// Query parameters dropped when normalizing links for deduplication. A
+/// trailing `*` matches any parameter with that prefix.
+const DEFAULT_TRACKING_PARAMS: &[&str] = &[
+    "utm_*", "fbclid", "gclid", "dclid", "msclkid", "mc_cid", "mc_eid", "igshid", "_ga", "ref_src",
+];
+
+/// The caller's tracking parameter list, else [`DEFAULT_TRACKING_PARAMS`].
+fn tracking_params(options: &ParseOptions) -> Vec<String> {
+    options.tracking_params.clone().unwrap_or_else(|| {
+        DEFAULT_TRACKING_PARAMS
+            .iter()
+            .map(|param| param.to_string())
+            .collect()
+    })
+}
+
+/// Description endings that mark an entry as a teaser for the full article.
+const DEFAULT_TRUNCATION_PHRASES: &[&str] = &[
+    "read more",
+    "continue reading",
+    "keep reading",
+    "read the full story",
+    "read the full article",
+    "read full story",
+    "read full article",
+    "full story",
+    "[\u{2026}]",
+    "[...]",
+];
+
+/// Longest paragraph, in characters, still read as a teaser when the
+/// description ends with a lone link right after it.
+const TEASER_MAX_CHARS: usize = 280;
+
+/// The caller's truncation phrases, else [`DEFAULT_TRUNCATION_PHRASES`],
+/// lowercased for matching.
+fn truncation_phrases(options: &ParseOptions) -> Vec<String> {
+    match &options.truncation_phrases {
+        Some(phrases) => phrases
+            .iter()
+            .map(|phrase| phrase.trim().to_lowercase())
+            .filter(|phrase| !phrase.is_empty())
+            .collect(),
+        None => DEFAULT_TRUNCATION_PHRASES
+            .iter()
+            .map(|phrase| phrase.to_string())
+            .collect(),
+    }
+}
+
+/// Whether a description looks like a teaser for an article that continues
+/// on the publisher's site: it ends with a continuation phrase, is shorter
+/// than the title, or is a short paragraph followed only by a link.
+fn looks_truncated(title: &str, plain: &str, raw: &str, phrases: &[String]) -> bool {
+    let ending = plain
+        .trim_end_matches(|c: char| {
+            c.is_whitespace()
+                || matches!(
+                    c,
+                    '.' | '\u{2026}' | ':' | '!' | '>' | '\u{bb}' | '\u{203a}' | '\u{2192}'
+                )
+        })
+        .to_lowercase();
+    if phrases
+        .iter()
+        .any(|phrase| ending.ends_with(phrase.as_str()))
+    {
+        return true;
+    }
+    let length = plain.chars().count();
+    if length > 0 && length < title.trim().chars().count() {
+        return true;
+    }
+    teaser_before_trailing_link(raw)
+}
+
+/// Whether raw description HTML ends with a link, ignoring closing `</p>`
+/// and `</div>` tags, right after no more than [`TEASER_MAX_CHARS`] of text.
+fn teaser_before_trailing_link(raw: &str) -> bool {
+    let lower = raw.to_ascii_lowercase();
+    let mut end = lower.trim_end();
+    while let Some(rest) = end
+        .strip_suffix("</p>")
+        .or_else(|| end.strip_suffix("</div>"))
+    {
+        end = rest.trim_end();
+    }
+    let Some(before_close) = end.strip_suffix("</a>") else {
+        return false;
+    };
+    let Some(link_start) = before_close
+        .match_indices("<a")
+        .map(|(index, _)| index)
+        .filter(|&index| {
+            before_close[index + 2..].starts_with(|c: char| c == '>' || c.is_ascii_whitespace())
+        })
+        .last()
+    else {
+        return false;
+    };
+    if before_close[link_start..].contains("</a>") {
+        return false;
+    }
+    let teaser = clean_html(&raw[..link_start]);
+    !teaser.is_empty() && teaser.chars().count() <= TEASER_MAX_CHARS
+}
+
+fn is_tracking_param(name: &str, tracking_params: &[String]) -> bool {
+    let name = name.to_ascii_lowercase();
+    tracking_params
+        .iter()
+        .any(|param| match param.strip_suffix('*') {
+            Some(prefix) => name.starts_with(&prefix.to_ascii_lowercase()),
+            None => name.eq_ignore_ascii_case(param),
+        })
+}
+
+/// Removes tracking query parameters from `url` in place, dropping the `?`
+/// entirely when nothing else remains. Returns whether any were removed.
+fn remove_tracking_params(url: &mut url::Url, tracking_params: &[String]) -> bool {
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    let kept: Vec<(String, String)> = pairs
+        .iter()
+        .filter(|(name, _)| !is_tracking_param(name, tracking_params))
+        .cloned()
+        .collect();
+    if kept.len() == pairs.len() {
+        return false;
+    }
+    if kept.is_empty() {
+        url.set_query(None);
+    } else {
+        url.query_pairs_mut().clear().extend_pairs(kept);
+    }
+    true
+}
+
+/// Strips tracking query parameters from an article link, leaving the rest
+/// of the URL as published. Links without tracking parameters, or that do
+/// not parse as URLs, are returned unchanged.
+fn strip_tracking_from_link(link: String, tracking_params: &[String]) -> String {
+    let Ok(mut url) = url::Url::parse(link.trim()) else {
+        return link;
+    };
+    if remove_tracking_params(&mut url, tracking_params) {
+        url.to_string()
+    } else {
+        link
+    }
+}
+
+/// Normalizes a link for duplicate detection: lowercased scheme and host,
+/// tracking query parameters removed, and no trailing slash. Links that do
+/// not parse as URLs are only trimmed.
+fn normalize_article_link(link: &str, tracking_params: &[String]) -> String {
+    let link = link.trim();
+    let Ok(mut url) = url::Url::parse(link) else {
+        return link.to_string();
+    };
+    remove_tracking_params(&mut url, tracking_params);
+
+    let mut normalized = url.to_string();
+    if url.query().is_none() && url.fragment().is_none() {
+        while normalized.ends_with('/') {
+            normalized.pop();
+        }
+    }
+    normalized
+}
+
+/// An article's guid, or its link when it has none, normalized for
+/// deduplication. A guid that is not a URL is scoped to the article's source,
+/// as two publishers may both number their entries `1`, `2`, and so on.
+/// Empty when the article has neither.
+fn dedupe_key(article: &ParsedArticle, tracking_params: &[String]) -> String {
+    match article.guid.as_deref().map(str::trim) {
+        Some(guid) if !guid.is_empty() && url::Url::parse(guid).is_err() => {
+            format!("{}\u{0}{guid}", article.source)
+        }
+        guid => normalize_article_link(guid.unwrap_or(&article.link), tracking_params),
+    }
+}
+
+/// Drops articles whose [`dedupe_key`] was already seen, walking sources in
+/// order, and resets each source's `article_count` to what remains.
+/// Preferring the guid keeps distinct entries that share or reuse a URL.
+fn dedupe_articles(groups: &mut [(Vec<ParsedArticle>, SourceStats)], tracking_params: &[String]) {
+    let mut seen = HashSet::new();
+    for (articles, stat) in groups.iter_mut() {
+        let before = articles.len();
+        articles.retain(|article| {
+            let key = dedupe_key(article, tracking_params);
+            key.is_empty() || seen.insert(key)
+        });
+        let removed = before - articles.len();
+        stat.article_count = stat.article_count.saturating_sub(removed);
+        stat.returned_count = articles.len();
+    }
+}
+
+fn fetch_result_url(result: &FetchResult) -> &str {
+    match result {
+        FetchResult::Success(raw) => &raw.url,
+        FetchResult::Error(err) => &err.url,
+        FetchResult::NotModified(unchanged) => &unchanged.url,
+    }
+}
+
+/// One sub-feed's parse outcome, before it is merged into its source.
+#[derive(Default)]
+struct SubFeedParse {
+    articles: Vec<ParsedArticle>,
+    stat: SubFeedStat,
+    /// Message joined into the source's `error_message`.
+    error: Option<String>,
+    /// Downgrades the source's status to "warning".
+    warning: bool,
+    parse_timeout: bool,
+    blocked_article_count: usize,
+    filtered_out_count: usize,
+    keyword_filtered_count: usize,
+    publish_times: Vec<chrono::DateTime<chrono::Utc>>,
+    feed_icon_url: Option<String>,
+    feed_title: Option<String>,
+    feed_description: Option<String>,
+    last_build_date: Option<chrono::DateTime<chrono::Utc>>,
+    ttl_minutes: Option<u32>,
+    poll_after: Option<chrono::DateTime<chrono::Utc>>,
+    parse_duration: Duration,
+}
+
+/// Parses one fetched sub-feed into articles and its sub-feed statistics,
+/// applying the per-feed filters.
+fn parse_sub_feed(
+    source_name: &str,
+    result: &FetchResult,
+    options: &ParseOptions,
+    deadline: Option<Instant>,
+) -> SubFeedParse {
+    let started = Instant::now();
+    let mut parsed = match result {
+        FetchResult::Success(raw) if deadline_passed(deadline) => SubFeedParse {
+            error: Some(PARSE_DEADLINE_MESSAGE.to_string()),
+            warning: true,
+            parse_timeout: true,
+            stat: SubFeedStat {
+                url: raw.url.clone(),
+                status: "skipped".to_string(),
+                error_message: Some(PARSE_DEADLINE_MESSAGE.to_string()),
+                error_kind: Some("timeout".to_string()),
+                fetch_duration_ms: raw.duration_ms,
+                body_bytes: raw.body_bytes,
+                http_version: raw.http_version.clone(),
+                status_code: raw.status_code,
+                final_url: raw.final_url.clone(),
+                redirects: raw.redirects.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        FetchResult::Success(raw) => match parse_feed(&raw.xml) {
+            Ok(feed) => {
+                let mut parsed = SubFeedParse {
+                    feed_icon_url: options
+                        .inline_icons
+                        .then(|| feed_icon_href(&feed, &raw.url))
+                        .flatten(),
+                    feed_title: feed_text(feed.title.as_ref()),
+                    feed_description: feed_text(feed.description.as_ref()),
+                    last_build_date: feed.updated,
+                    ttl_minutes: feed.ttl,
+                    poll_after: suggested_poll_after(raw.fresh_until, feed.ttl),
+                    ..Default::default()
+                };
+                let service_error_suspect =
+                    options.detect_service_errors && looks_like_service_error(&feed);
+                parsed.publish_times = feed
+                    .entries
+                    .iter()
+                    .filter_map(|entry| entry.published.or(entry.updated))
+                    .collect();
+                parsed.filtered_out_count = feed
+                    .entries
+                    .iter()
+                    .filter(|entry| published_before(entry, options.since))
+                    .count();
+                let mut parsed_articles = extract_articles(
+                    feed.entries,
+                    feed_document(&raw.xml),
+                    source_name,
+                    &raw.url,
+                    options,
+                    deadline,
+                );
+                let duplicate_guid_count =
+                    resolve_duplicate_guids(&mut parsed_articles, options.duplicate_guid_policy);
+                let partial = deadline_passed(deadline);
+                if partial {
+                    parsed.error = Some(PARTIAL_PARSE_MESSAGE.to_string());
+                    parsed.parse_timeout = true;
+                    parsed.warning = true;
+                }
+                if !options.blocked_domains.is_empty() {
+                    let before = parsed_articles.len();
+                    parsed_articles.retain(|article| {
+                        !is_blocked_link(&article.link, &options.blocked_domains)
+                    });
+                    parsed.blocked_article_count = before - parsed_articles.len();
+                }
+                if options.keyword_filter.is_active() {
+                    let before = parsed_articles.len();
+                    parsed_articles.retain(|article| {
+                        options
+                            .keyword_filter
+                            .keeps(&article.title, &article.description)
+                    });
+                    parsed.keyword_filtered_count = before - parsed_articles.len();
+                }
+                let count = parsed_articles.len();
+                if let Some(progress) = &options.progress {
+                    progress.add_articles(count);
+                }
+                let new_article_count = options
+                    .previous_article_ids
+                    .get(&raw.url)
+                    .map(|seen| count_new_articles(&parsed_articles, seen));
+                parsed.articles = parsed_articles;
+                parsed.stat = SubFeedStat {
+                    url: raw.url.clone(),
+                    status: if partial { "partial" } else { "success" }.to_string(),
+                    article_count: count,
+                    error_message: parsed.error.clone(),
+                    error_kind: None,
+                    fetch_duration_ms: raw.duration_ms,
+                    body_bytes: raw.body_bytes,
+                    timed_out: false,
+                    new_article_count,
+                    http_version: raw.http_version.clone(),
+                    status_code: raw.status_code,
+                    final_url: raw.final_url.clone(),
+                    redirects: raw.redirects.clone(),
+                    retry_after_secs: None,
+                    service_error_suspect,
+                    duplicate_guid_count,
+                    etag: raw.validators.etag.clone(),
+                    last_modified: raw.validators.last_modified.clone(),
+                    charset: raw.charset.clone(),
+                    content_type: raw.content_type.clone(),
+                    suggested_poll_after: parsed
+                        .poll_after
+                        .map(|time| format_published(Some(time), options.normalize_dates_utc)),
+                };
+                parsed
+            }
+            Err(err) => {
+                let msg = if is_html_response(raw) {
+                    HTML_NOT_FEED_MESSAGE.to_string()
+                } else {
+                    format!("Parse error: {err}")
+                };
+                SubFeedParse {
+                    error: Some(msg.clone()),
+                    warning: true,
+                    poll_after: raw.fresh_until,
+                    stat: SubFeedStat {
+                        url: raw.url.clone(),
+                        status: "error".to_string(),
+                        article_count: 0,
+                        error_message: Some(msg),
+                        error_kind: Some("parse".to_string()),
+                        fetch_duration_ms: raw.duration_ms,
+                        body_bytes: raw.body_bytes,
+                        timed_out: false,
+                        new_article_count: None,
+                        http_version: raw.http_version.clone(),
+                        status_code: raw.status_code,
+                        final_url: raw.final_url.clone(),
+                        redirects: raw.redirects.clone(),
+                        retry_after_secs: None,
+                        service_error_suspect: false,
+                        duplicate_guid_count: 0,
+                        etag: raw.validators.etag.clone(),
+                        last_modified: raw.validators.last_modified.clone(),
+                        charset: raw.charset.clone(),
+                        content_type: raw.content_type.clone(),
+                        suggested_poll_after: raw
+                            .fresh_until
+                            .map(|time| format_published(Some(time), options.normalize_dates_utc)),
+                    },
+                    ..Default::default()
+                }
+            }
+        },
+        FetchResult::Error(err) => SubFeedParse {
+            error: Some(err.message.clone()),
+            warning: true,
+            stat: SubFeedStat {
+                url: err.url.clone(),
+                status: "error".to_string(),
+                article_count: 0,
+                error_message: Some(err.message.clone()),
+                error_kind: Some(err.error_kind.clone()),
+                fetch_duration_ms: err.duration_ms,
+                body_bytes: err.body_bytes,
+                timed_out: err.timed_out,
+                new_article_count: None,
+                http_version: err.http_version.clone(),
+                status_code: err.status_code,
+                final_url: err.final_url.clone(),
+                redirects: Vec::new(),
+                retry_after_secs: err.retry_after_secs,
+                service_error_suspect: false,
+                duplicate_guid_count: 0,
+                etag: None,
+                last_modified: None,
+                charset: None,
+                content_type: None,
+                suggested_poll_after: None,
+            },
+            ..Default::default()
+        },
+        FetchResult::NotModified(unchanged) => SubFeedParse {
+            stat: SubFeedStat {
+                url: unchanged.url.clone(),
+                status: "not_modified".to_string(),
+                fetch_duration_ms: unchanged.duration_ms,
+                http_version: unchanged.http_version.clone(),
+                status_code: unchanged.status_code,
+                final_url: unchanged.final_url.clone(),
+                etag: unchanged.validators.etag.clone(),
+                last_modified: unchanged.validators.last_modified.clone(),
+                suggested_poll_after: unchanged
+                    .fresh_until
+                    .map(|time| format_published(Some(time), options.normalize_dates_utc)),
+                ..Default::default()
+            },
+            poll_after: unchanged.fresh_until,
+            ..Default::default()
+        },
+    };
+    parsed.parse_duration = started.elapsed();
+    parsed
+}
+
+/// Combines a source's parsed sub-feeds, in request order, into its
+/// articles and [`SourceStats`].
+fn merge_sub_feeds(
     source_name: &str,
     results: &[FetchResult],
+    parsed: Vec<SubFeedParse>,
+    options: &ParseOptions,
 ) -> (Vec<ParsedArticle>, SourceStats) {
     let mut articles = Vec::new();
     let mut sub_stats = Vec::new();
     let mut top_status = "success".to_string();
     let mut errors = Vec::new();
+    let mut parse_timeout = false;
+    let mut blocked_article_count = 0;
+    let mut publish_times = Vec::new();
+    let mut feed_icon_url = None;
+    let mut feed_title = None;
+    let mut feed_description = None;
+    let mut last_build_date: Option<chrono::DateTime<chrono::Utc>> = None;
+    let mut ttl_minutes: Option<u32> = None;
+    let mut poll_after: Option<chrono::DateTime<chrono::Utc>> = None;
+    let mut filtered_out_count = 0;
+    let mut keyword_filtered_count = 0;
+    let mut merged_duplicate_count = 0;
+    let mut parse_duration = Duration::ZERO;
+    let mut earlier_sub_feed_keys = HashSet::new();
+    let dedupe_params = if options.dedupe_within_source {
+        tracking_params(options)
+    } else {
+        Vec::new()
+    };
 
-    for result in results {
-        match result {
-            FetchResult::Success(raw) => {
-                match parser::parse(trim_to_feed_document(&raw.xml).as_bytes()) {
-                    Ok(feed) => {
-                        let parsed_articles = extract_articles(
-                            feed.entries,
-                            trim_to_feed_document(&raw.xml),
-                            source_name,
-                        );
-                        let count = parsed_articles.len();
-                        articles.extend(parsed_articles);
-                        sub_stats.push(SubFeedStat {
-                            url: raw.url.clone(),
-                            status: "success".to_string(),
-                            article_count: count,
-                            error_message: None,
-                            fetch_duration_ms: raw.duration_ms,
-                            timed_out: false,
-                        });
-                    }
-                    Err(err) => {
-                        top_status = "warning".to_string();
-                        let msg = format!("Parse error: {err}");
-                        errors.push(msg.clone());
-                        sub_stats.push(SubFeedStat {
-                            url: raw.url.clone(),
-                            status: "error".to_string(),
-                            article_count: 0,
-                            error_message: Some(msg),
-                            fetch_duration_ms: raw.duration_ms,
-                            timed_out: false,
-                        });
-                    }
-                }
-            }
-            FetchResult::Error(err) => {
-                top_status = "warning".to_string();
-                errors.push(err.message.clone());
-                sub_stats.push(SubFeedStat {
-                    url: err.url.clone(),
-                    status: "error".to_string(),
-                    article_count: 0,
-                    error_message: Some(err.message.clone()),
-                    fetch_duration_ms: err.duration_ms,
-                    timed_out: err.timed_out,
-                });
-            }
+    for mut sub_feed in parsed {
+        if sub_feed.warning {
+            top_status = "warning".to_string();
+        }
+        errors.extend(sub_feed.error);
+        parse_timeout |= sub_feed.parse_timeout;
+        blocked_article_count += sub_feed.blocked_article_count;
+        filtered_out_count += sub_feed.filtered_out_count;
+        keyword_filtered_count += sub_feed.keyword_filtered_count;
+        publish_times.append(&mut sub_feed.publish_times);
+        feed_icon_url = feed_icon_url.or(sub_feed.feed_icon_url);
+        feed_title = feed_title.or(sub_feed.feed_title);
+        feed_description = feed_description.or(sub_feed.feed_description);
+        last_build_date = last_build_date.max(sub_feed.last_build_date);
+        if let Some(ttl) = sub_feed.ttl_minutes {
+            ttl_minutes = Some(ttl_minutes.map_or(ttl, |min| min.min(ttl)));
+        }
+        if let Some(time) = sub_feed.poll_after {
+            poll_after = Some(poll_after.map_or(time, |earliest| earliest.min(time)));
+        }
+        parse_duration += sub_feed.parse_duration;
+
+        if options.dedupe_within_source {
+            // Only entries an earlier sub-feed already returned are merged;
+            // repeats within one feed are governed by `duplicate_guid_policy`.
+            let before = sub_feed.articles.len();
+            sub_feed.articles.retain(|article| {
+                let key = dedupe_key(article, &dedupe_params);
+                key.is_empty() || !earlier_sub_feed_keys.contains(&key)
+            });
+            merged_duplicate_count += before - sub_feed.articles.len();
+            earlier_sub_feed_keys.extend(
+                sub_feed
+                    .articles
+                    .iter()
+                    .map(|article| dedupe_key(article, &dedupe_params)),
+            );
+        }
+        articles.append(&mut sub_feed.articles);
+        sub_stats.push(sub_feed.stat);
+    }
+
+    let dates_synthesized =
+        !articles.is_empty() && articles.iter().all(|article| article.published_synthesized);
+    if dates_synthesized {
+        top_status = "warning".to_string();
+        errors.push(NO_PUBLISH_DATES_MESSAGE.to_string());
+    }
+
+    let article_count = articles.len();
+    if let Some(limit) = options.max_articles_per_source {
+        sort_articles_newest_first(&mut articles);
+        articles.truncate(limit);
+    }
+
+    let mut error_kinds: Vec<String> = Vec::new();
+    for kind in sub_stats.iter().filter_map(|sub| sub.error_kind.as_ref()) {
+        if !error_kinds.contains(kind) {
+            error_kinds.push(kind.clone());
         }
     }
 
     let stat = SourceStats {
         name: source_name.to_string(),
         status: top_status,
-        article_count: articles.len(),
+        article_count,
+        returned_count: articles.len(),
         error_message: if errors.is_empty() {
             None
         } else {
             Some(errors.join("; "))
         },
+        error_kinds,
         sub_feeds: if sub_stats.is_empty() {
             None
         } else {
             Some(sub_stats)
         },
+        parse_timeout,
+        blocked_article_count,
+        filtered_out_count,
+        keyword_filtered_count,
+        merged_duplicate_count,
+        avg_publish_interval_minutes: median_publish_interval_minutes(publish_times),
+        feed_icon_url,
+        feed_icon_data: None,
+        feed_title,
+        feed_description,
+        last_build_date: last_build_date
+            .map(|date| format_published(Some(date), options.normalize_dates_utc)),
+        ttl_minutes,
+        suggested_poll_after: poll_after
+            .map(|time| format_published(Some(time), options.normalize_dates_utc)),
+        dates_synthesized,
+        fetch_duration_ms: results
+            .iter()
+            .map(fetch_result_duration_ms)
+            .max()
+            .unwrap_or(0),
+        parse_duration_ms: parse_duration.as_millis(),
     };
 
     (articles, stat)
 }
 
-fn extract_articles(
-    entries: Vec<feed_rs::model::Entry>,
-    raw_xml: &str,
-    source_name: &str,
-) -> Vec<ParsedArticle> {
-    let item_metadata = extract_rss_item_metadata(raw_xml);
-    entries
-        .into_par_iter()
-        .enumerate()
-        .filter_map(|entry| {
-            let (index, entry) = entry;
-            let title = clean_html(entry.title.as_ref()?.content.as_ref());
-            let link = entry.links.first()?.href.clone();
+/// The most conservative time to poll a feed again: the later of when the
+/// response stops being fresh and the feed's `<ttl>` from now.
+fn suggested_poll_after(
+    fresh_until: Option<chrono::DateTime<chrono::Utc>>,
+    ttl_minutes: Option<u32>,
+) -> Option<chrono::DateTime<chrono::Utc>> {
+    let ttl_expiry = ttl_minutes
+        .map(|minutes| chrono::Utc::now() + chrono::Duration::minutes(i64::from(minutes)));
+    fresh_until.max(ttl_expiry)
+}
 
-            let description = pick_description(&entry).unwrap_or_default();
-            let description = clean_html(&description);
+/// Resolves the feed's declared icon, falling back to its logo, against the
+/// feed URL.
+fn feed_icon_href(feed: &feed_rs::model::Feed, feed_url: &str) -> Option<String> {
+    let href = feed
+        .icon
+        .as_ref()
+        .or(feed.logo.as_ref())
+        .map(|image| image.uri.trim())
+        .filter(|uri| !uri.is_empty())?;
+    url::Url::parse(feed_url)
+        .and_then(|base| base.join(href))
+        .map(|resolved| resolved.to_string())
+        .ok()
+}
 
-            let published = entry
-                .published
-                .or(entry.updated)
-                .map(|dt| dt.to_rfc3339())
-                .unwrap_or_else(|| chrono::Utc::now().to_rfc3339());
+/// Phrases RSS bridges and proxies (Nitter, RSS-Bridge, RSSHub) put in the
+/// channel title or description when they return an error page as a feed.
+const SERVICE_ERROR_PHRASES: &[&str] = &[
+    "rate limit",
+    "rate-limit",
+    "ratelimit",
+    "too many requests",
+    "error",
+    "exception",
+    "unavailable",
+    "not found",
+    "suspended",
+    "forbidden",
+    "blocked",
+    "try again later",
+];
+
+/// Whether a parsed feed has no entries and an error-like title or
+/// description, suggesting the upstream service failed behind a 200.
+fn looks_like_service_error(feed: &feed_rs::model::Feed) -> bool {
+    if !feed.entries.is_empty() {
+        return false;
+    }
+    let text = [feed.title.as_ref(), feed.description.as_ref()]
+        .into_iter()
+        .flatten()
+        .map(|text| text.content.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(" ");
+    SERVICE_ERROR_PHRASES
+        .iter()
+        .any(|phrase| text.contains(phrase))
+}
 
-            let image = pick_image(&entry);
-            let category = entry
-                .categories
-                .first()
-                .and_then(|c| c.label.clone())
-                .or_else(|| entry.categories.first().map(|c| c.term.clone()));
+/// Fewest dated articles needed before estimating a publishing cadence.
+const MIN_DATED_ARTICLES_FOR_INTERVAL: usize = 3;
 
-            let (mut authors, mut author_urls) = extract_entry_authors(&entry);
-            if authors.is_empty() {
-                (authors, author_urls) =
-                    find_rss_item_authors(&item_metadata, &link, &title, index);
-            }
+fn median_publish_interval_minutes(
+    mut publish_times: Vec<chrono::DateTime<chrono::Utc>>,
+) -> Option<f64> {
+    publish_times.sort();
+    publish_times.dedup();
+    if publish_times.len() < MIN_DATED_ARTICLES_FOR_INTERVAL {
+        return None;
+    }
 
-            Some(ParsedArticle {
-                title,
-                link,
-                description,
-                published,
-                source: source_name.to_string(),
-                authors,
-                author_urls,
-                image,
-                category,
-            })
-        })
-        .collect()
+    let mut gaps: Vec<f64> = publish_times
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).num_seconds() as f64 / 60.0)
+        .collect();
+    gaps.sort_by(f64::total_cmp);
+    let middle = gaps.len() / 2;
+    Some(if gaps.len().is_multiple_of(2) {
+        (gaps[middle - 1] + gaps[middle]) / 2.0
+    } else {
+        gaps[middle]
+    })
 }
 
-fn pick_description(entry: &feed_rs::model::Entry) -> Option<String> {
-    if let Some(summary) = &entry.summary {
-        return Some(summary.content.clone());
-    }
+const PARSE_DEADLINE_MESSAGE: &str = "Skipped: parse deadline exceeded";
 
-    if let Some(Content {
-        body: Some(body), ..
-    }) = &entry.content
-    {
-        return Some(body.clone());
-    }
+/// Note for a sub-feed whose entries stopped being extracted at the deadline.
+const PARTIAL_PARSE_MESSAGE: &str = "Partial: parse deadline exceeded during extraction";
 
-    entry
-        .links
-        .first()
-        .map(|link| link.title.clone().unwrap_or_default())
+/// Warning for a source whose articles all fell back to the parse time.
+const NO_PUBLISH_DATES_MESSAGE: &str = "feed provides no publish dates";
+
+fn deadline_passed(deadline: Option<Instant>) -> bool {
+    deadline.is_some_and(|deadline| Instant::now() >= deadline)
 }
 
-fn pick_image(entry: &feed_rs::model::Entry) -> Option<String> {
-    if let Some(media) = entry.media.first() {
-        if let Some(content) = media.content.first() {
-            if let Some(url) = &content.url {
-                return Some(url.to_string());
+/// Whether the link's host is a blocked domain or one of its subdomains.
+fn is_blocked_link(link: &str, blocked_domains: &HashSet<String>) -> bool {
+    let Some(host) = url::Url::parse(link)
+        .ok()
+        .and_then(|parsed| parsed.host_str().map(str::to_lowercase))
+    else {
+        return false;
+    };
+    let mut candidate = host.trim_end_matches('.');
+    loop {
+        if blocked_domains.contains(candidate) {
+            return true;
+        }
+        match candidate.split_once('.') {
+            Some((_, parent)) => candidate = parent,
+            None => return false,
+        }
+    }
+}
+
+fn count_new_articles(articles: &[ParsedArticle], seen: &HashSet<String>) -> usize {
+    articles
+        .iter()
+        .filter(|article| {
+            article
+                .guid
+                .as_ref()
+                .is_some_and(|guid| !seen.contains(guid))
+        })
+        .count()
+}
+
+/// Applies `policy` to articles whose guid repeats an earlier one in the same
+/// sub-feed and returns how many repeats there were.
+fn resolve_duplicate_guids(articles: &mut [ParsedArticle], policy: DuplicateGuidPolicy) -> usize {
+    let mut seen = HashSet::new();
+    let mut duplicates = 0;
+    for article in articles.iter_mut() {
+        let Some(guid) = &article.guid else {
+            continue;
+        };
+        if seen.insert(guid.clone()) {
+            continue;
+        }
+        duplicates += 1;
+        match policy {
+            DuplicateGuidPolicy::Ignore => {}
+            DuplicateGuidPolicy::FallbackToLink => {
+                if !article.link.trim().is_empty() {
+                    article.guid = Some(article.link.trim().to_string());
+                    article.sort_key = article_sort_key(article);
+                }
+            }
+            DuplicateGuidPolicy::Flag => article.duplicate_guid = true,
+        }
+    }
+    duplicates
+}
+
+/// Error for a sub-feed whose URL serves a web page instead of a feed.
+const HTML_NOT_FEED_MESSAGE: &str = "URL returned HTML, not a feed. Did you mean to use feed \
+     autodiscovery? discover_feeds(url) lists the feeds the page links to.";
+
+/// Whether a response that failed to parse as a feed is a web page instead:
+/// it was served as HTML, or has a `<!doctype html>` or `<html>` tag near
+/// the start whatever its `Content-Type` claims.
+fn is_html_response(raw: &RawFeed) -> bool {
+    let served_as_html = raw.content_type.as_deref().is_some_and(|content_type| {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        mime == "text/html" || mime == "application/xhtml+xml"
+    });
+    let head: String = raw
+        .xml
+        .chars()
+        .take(1_024)
+        .collect::<String>()
+        .to_lowercase();
+    served_as_html || head.contains("<!doctype html") || head.contains("<html")
+}
+
+/// Checks one fetched URL without running the rest of the pipeline: whether
+/// the body parses as a feed, which format it is, its title, and how many
+/// entries it has.
+pub fn validate_fetch_result(result: &FetchResult) -> FeedValidation {
+    let raw = match result {
+        FetchResult::Success(raw) => raw,
+        FetchResult::Error(err) => {
+            return FeedValidation {
+                error: Some(err.message.clone()),
+                ..Default::default()
+            }
+        }
+        FetchResult::NotModified(_) => {
+            return FeedValidation {
+                error: Some("Server answered 304 Not Modified".to_string()),
+                ..Default::default()
             }
         }
+    };
+    match parse_feed(&raw.xml) {
+        Ok(feed) => FeedValidation {
+            ok: true,
+            feed_title: feed_text(feed.title.as_ref()),
+            article_count: feed.entries.len(),
+            feed_type: Some(
+                match feed.feed_type {
+                    feed_rs::model::FeedType::Atom => "atom",
+                    feed_rs::model::FeedType::JSON => "json",
+                    feed_rs::model::FeedType::RSS0
+                    | feed_rs::model::FeedType::RSS1
+                    | feed_rs::model::FeedType::RSS2 => "rss",
+                }
+                .to_string(),
+            ),
+            error: None,
+        },
+        Err(_) if is_html_response(raw) => FeedValidation {
+            feed_type: Some("html".to_string()),
+            error: Some(HTML_NOT_FEED_MESSAGE.to_string()),
+            ..Default::default()
+        },
+        Err(err) => FeedValidation {
+            error: Some(format!("Parse error: {err}")),
+            ..Default::default()
+        },
+    }
+}
+
+/// Parses a fetched feed body, leaving missing entry ids empty. feed-rs would
+/// otherwise invent a hash of the link and title, hiding that the feed had no
+/// guid.
+fn parse_feed(xml: &str) -> Result<feed_rs::model::Feed, parser::ParseFeedError> {
+    parser::Builder::new()
+        .id_generator(|_links, _title, _uri| String::new())
+        .build()
+        .parse(feed_document(xml).as_bytes())
+}
+
+fn stable_entry_id(entry: &feed_rs::model::Entry, link: &str) -> Option<String> {
+    let id = entry.id.trim();
+    if !id.is_empty() {
+        return Some(id.to_string());
+    }
+    let link = link.trim();
+    if link.is_empty() {
+        return None;
+    }
+    Some(link.to_string())
+}
+
+/// Cleaned feed-level text such as the channel title, or `None` when it is
+/// missing or blank.
+fn feed_text(text: Option<&feed_rs::model::Text>) -> Option<String> {
+    let cleaned = clean_html(&text?.content);
+    (!cleaned.is_empty()).then_some(cleaned)
+}
+
+/// Formats an entry date as RFC 3339, defaulting to now when it has none.
+/// feed-rs already converts dates to UTC; `utc_z` only swaps the `+00:00`
+/// offset for `Z`.
+fn format_published(published: Option<chrono::DateTime<chrono::Utc>>, utc_z: bool) -> String {
+    let published = published.unwrap_or_else(chrono::Utc::now);
+    if utc_z {
+        published.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+    } else {
+        published.to_rfc3339()
+    }
+}
+
+/// Whether the entry's published (else updated) date is older than the
+/// `since` cutoff. Undated entries are never considered older.
+fn published_before(
+    entry: &feed_rs::model::Entry,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+) -> bool {
+    let Some(since) = since else {
+        return false;
+    };
+    entry
+        .published
+        .or(entry.updated)
+        .is_some_and(|published| published < since)
+}
+
+fn extract_articles(
+    entries: Vec<feed_rs::model::Entry>,
+    raw_xml: &str,
+    source_name: &str,
+    feed_url: &str,
+    options: &ParseOptions,
+    deadline: Option<Instant>,
+) -> Vec<ParsedArticle> {
+    let item_metadata = extract_rss_item_metadata(raw_xml);
+    let json_images = if is_json_document(raw_xml) {
+        extract_json_feed_images(raw_xml)
+    } else {
+        HashMap::new()
+    };
+    let link_tracking_params = options
+        .strip_tracking_params
+        .then(|| tracking_params(options));
+    let truncation_phrases = truncation_phrases(options);
+    let url_category = if options.infer_category_from_url {
+        infer_category_from_feed_url(feed_url)
+    } else {
+        None
+    };
+    entries
+        .into_par_iter()
+        .enumerate()
+        .filter_map(|entry| {
+            let (index, entry) = entry;
+            if deadline_passed(deadline) || published_before(&entry, options.since) {
+                return None;
+            }
+            let mut title = clean_html_with_spacing(
+                entry.title.as_ref()?.content.as_ref(),
+                options.keep_ideographic_spaces,
+            );
+            if options.strip_decorative_chars {
+                title = strip_decorative_chars(
+                    &title,
+                    options.strip_emoji,
+                    options.keep_ideographic_spaces,
+                );
+            }
+            let link = entry.links.first()?.href.clone();
+
+            let raw_description =
+                pick_description(&entry, options.prefer_full_content).unwrap_or_default();
+            // Summaries and the teaser check work on plain text whatever the
+            // description format, so markup is never split.
+            let plain_description =
+                clean_html_with_spacing(&raw_description, options.keep_ideographic_spaces);
+            let summary = options
+                .summary_max_chars
+                .map(|max_chars| truncate_at_word_boundary(&plain_description, max_chars))
+                .unwrap_or_default();
+            let likely_truncated = looks_truncated(
+                &title,
+                &plain_description,
+                &raw_description,
+                &truncation_phrases,
+            );
+            let description = match options.description_format {
+                DescriptionFormat::Markdown => clean_html_to_markdown(&raw_description),
+                DescriptionFormat::SafeHtml => sanitize_html(&raw_description),
+                DescriptionFormat::Text if options.preserve_paragraphs => {
+                    clean_html_preserve_paragraphs(
+                        &raw_description,
+                        options.keep_ideographic_spaces,
+                    )
+                }
+                DescriptionFormat::Text => plain_description,
+            };
+
+            let published_at = entry.published.or(entry.updated);
+            let published = format_published(published_at, options.normalize_dates_utc);
+
+            let image_base = url::Url::parse(&link).ok();
+            let image = pick_image(&entry)
+                .or_else(|| json_images.get(entry.id.trim()).cloned())
+                .map(|image| absolutize_image_url(&image, image_base.as_ref()));
+            let images = extract_images(&entry, image.as_deref(), image_base.as_ref());
+            let mut categories = extract_categories(&entry);
+            let category_inferred = categories.is_empty() && url_category.is_some();
+            if category_inferred {
+                categories.extend(url_category.clone());
+            }
+            let category = categories.first().cloned();
+
+            let guid = stable_entry_id(&entry, &link);
+
+            let (mut authors, mut author_urls) = extract_entry_authors(&entry);
+            if authors.is_empty() {
+                (authors, author_urls) =
+                    find_rss_item_authors(&item_metadata, &link, &title, index);
+            }
+
+            let content_text = if options.extract_full_content {
+                pick_content_text(&entry)
+            } else {
+                None
+            };
+
+            let event = if options.extract_events {
+                extract_entry_event(&entry, &item_metadata, &link, &title, index)
+            } else {
+                None
+            };
+
+            let (word_count, reading_time_minutes) =
+                if options.description_format == DescriptionFormat::SafeHtml {
+                    reading_stats(&clean_html(&description))
+                } else {
+                    reading_stats(&description)
+                };
+
+            let sentiment_score = options.compute_sentiment.then(|| {
+                options
+                    .sentiment_lexicon
+                    .score(&format!("{title} {description}"))
+            });
+
+            let link = match &link_tracking_params {
+                Some(params) => strip_tracking_from_link(link, params),
+                None => link,
+            };
+
+            let mut article = ParsedArticle {
+                title,
+                link,
+                description,
+                summary,
+                likely_truncated,
+                published,
+                published_synthesized: published_at.is_none(),
+                source: source_name.to_string(),
+                authors,
+                author_urls,
+                image,
+                category,
+                categories,
+                guid,
+                event,
+                content_text,
+                category_inferred,
+                sentiment_score,
+                duplicate_guid: false,
+                sort_key: String::new(),
+                content_hash: String::new(),
+                word_count,
+                reading_time_minutes,
+                enclosures: extract_enclosures(&entry),
+                images,
+            };
+            article.sort_key = article_sort_key(&article);
+            article.content_hash = content_hash(&article);
+            Some(article)
+        })
+        .collect()
+}
+
+/// Picks the description source: the summary before the content body by
+/// default, or the full body first when `prefer_full_content` is set. Falls
+/// back to the first link's title.
+fn pick_description(entry: &feed_rs::model::Entry, prefer_full_content: bool) -> Option<String> {
+    let summary = entry.summary.as_ref().map(|summary| &summary.content);
+    let body = match &entry.content {
+        Some(Content {
+            body: Some(body), ..
+        }) if !body.trim().is_empty() => Some(body),
+        _ => None,
+    };
+    let preferred = if prefer_full_content {
+        body.or(summary)
+    } else {
+        summary.or(body)
+    };
+    if let Some(description) = preferred {
+        return Some(description.clone());
+    }
+
+    entry
+        .links
+        .first()
+        .map(|link| link.title.clone().unwrap_or_default())
+}
+
+const GENERIC_FEED_PATH_SEGMENTS: &[&str] = &[
+    "api",
+    "atom",
+    "default",
+    "en",
+    "export",
+    "feed",
+    "feeds",
+    "index",
+    "latest",
+    "main",
+    "news",
+    "rdf",
+    "rss",
+    "rss2",
+    "services",
+    "syndication",
+    "xml",
+];
+
+/// Collects every category label (the term when there is no label), trimmed
+/// and deduplicated case-insensitively, keeping the first spelling seen.
+fn extract_categories(entry: &feed_rs::model::Entry) -> Vec<String> {
+    let mut seen = HashSet::new();
+    entry
+        .categories
+        .iter()
+        .map(|category| category.label.as_deref().unwrap_or(&category.term).trim())
+        .filter(|name| !name.is_empty() && seen.insert(name.to_lowercase()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Guesses a section name from the last meaningful feed URL path segment,
+/// e.g. `/rss/technology.xml` yields `Technology`.
+fn infer_category_from_feed_url(feed_url: &str) -> Option<String> {
+    let parsed = url::Url::parse(feed_url).ok()?;
+    let segment = parsed
+        .path_segments()?
+        .rev()
+        .map(|segment| {
+            let lowered = segment.to_lowercase();
+            match lowered.rsplit_once('.') {
+                Some((stem, _)) => stem.to_string(),
+                None => lowered,
+            }
+        })
+        .find(|segment| {
+            !segment.is_empty()
+                && !GENERIC_FEED_PATH_SEGMENTS.contains(&segment.as_str())
+                && !segment.chars().all(|ch| ch.is_ascii_digit())
+        })?;
+
+    let words: Vec<String> = segment
+        .split(['-', '_', '+'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    Some(words.join(" "))
+}
+
+fn pick_content_text(entry: &feed_rs::model::Entry) -> Option<String> {
+    let body = entry.content.as_ref()?.body.as_deref()?;
+    let text = clean_html(body);
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// The entry's lead image: the first attached media that is an image, else
+/// an image-typed link. Audio and video media are left to `enclosures`.
+fn pick_image(entry: &feed_rs::model::Entry) -> Option<String> {
+    if let Some(media) =
+        media_candidates(entry).find(|media| is_image_media(media.mime_type.as_deref(), &media.url))
+    {
+        return Some(media.url);
     }
 
     if let Some(link) = entry
@@ -606,6 +2001,102 @@ fn pick_image(entry: &feed_rs::model::Entry) -> Option<String> {
     None
 }
 
+/// Makes an image URL absolute: protocol-relative `//host/...` URLs get
+/// `https:`, and relative paths are joined onto `base` (the article link).
+/// Absolute URLs, and relative ones without a usable base, pass through.
+fn absolutize_image_url(image: &str, base: Option<&url::Url>) -> String {
+    let image = image.trim();
+    if image.starts_with("//") {
+        return format!("https:{image}");
+    }
+    match url::Url::parse(image) {
+        Ok(_) => image.to_string(),
+        Err(_) => base
+            .and_then(|base| base.join(image).ok())
+            .map(|resolved| resolved.to_string())
+            .unwrap_or_else(|| image.to_string()),
+    }
+}
+
+/// File extensions that mark untyped media as audio or video.
+const AUDIO_VIDEO_EXTENSIONS: &[&str] = &[
+    ".mp3", ".m4a", ".aac", ".ogg", ".oga", ".opus", ".wav", ".flac", ".mp4", ".m4v", ".mov",
+    ".webm", ".mkv", ".avi",
+];
+
+/// Whether attached media counts as an image. Only media typed `audio/*` or
+/// `video/*` is excluded; untyped media is an image unless its URL names an
+/// audio or video file.
+fn is_image_media(media_type: Option<&str>, url: &str) -> bool {
+    match media_type {
+        Some(media_type) => !(media_type.starts_with("audio/") || media_type.starts_with("video/")),
+        None => {
+            let path = url
+                .split(['?', '#'])
+                .next()
+                .unwrap_or_default()
+                .to_lowercase();
+            !AUDIO_VIDEO_EXTENSIONS.iter().any(|ext| path.ends_with(ext))
+        }
+    }
+}
+
+/// Lists the entry's attached media in document order: RSS `<enclosure>` and
+/// Media RSS content, then Atom `rel="enclosure"` links.
+fn media_candidates(entry: &feed_rs::model::Entry) -> impl Iterator<Item = Enclosure> + '_ {
+    let media = entry
+        .media
+        .iter()
+        .flat_map(|media| media.content.iter())
+        .filter_map(|content| {
+            Some(Enclosure {
+                url: content.url.as_ref()?.to_string(),
+                mime_type: content.content_type.as_ref().map(|mime| mime.to_string()),
+                length_bytes: content.size,
+            })
+        });
+    let links = entry
+        .links
+        .iter()
+        .filter(|link| link.rel.as_deref() == Some("enclosure"))
+        .map(|link| Enclosure {
+            url: link.href.clone(),
+            mime_type: link.media_type.clone(),
+            length_bytes: link.length,
+        });
+    media.chain(links)
+}
+
+/// Collects the entry's non-image media, deduplicated by URL.
+fn extract_enclosures(entry: &feed_rs::model::Entry) -> Vec<Enclosure> {
+    let mut seen = HashSet::new();
+    media_candidates(entry)
+        .filter(|enclosure| !is_image_media(enclosure.mime_type.as_deref(), &enclosure.url))
+        .filter(|enclosure| seen.insert(enclosure.url.clone()))
+        .collect()
+}
+
+/// Collects every image URL attached to the entry, made absolute against
+/// `base` and deduplicated in order. `primary` (the article's lead image)
+/// always comes first when present.
+fn extract_images(
+    entry: &feed_rs::model::Entry,
+    primary: Option<&str>,
+    base: Option<&url::Url>,
+) -> Vec<String> {
+    let mut seen = HashSet::new();
+    primary
+        .map(str::to_string)
+        .into_iter()
+        .chain(
+            media_candidates(entry)
+                .filter(|media| is_image_media(media.mime_type.as_deref(), &media.url))
+                .map(|media| absolutize_image_url(&media.url, base)),
+        )
+        .filter(|url| seen.insert(url.clone()))
+        .collect()
+}
+
 fn matches_media_image(media_type: Option<&str>) -> bool {
     media_type
         .map(|t| t.starts_with("image/") || t == "application/octet-stream")
@@ -614,7 +2105,58 @@ fn matches_media_image(media_type: Option<&str>) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::{extract_rss_item_metadata, split_author_name, trim_to_feed_document};
+    use std::collections::{BTreeMap, HashMap, HashSet};
+    use std::time::Duration;
+
+    use super::{
+        article_sort_key, clean_html, content_hash, count_new_articles, dedupe_articles,
+        extract_rss_item_metadata, group_articles_by_source, infer_category_from_feed_url,
+        is_blocked_link, looks_like_service_error, looks_truncated,
+        median_publish_interval_minutes, merge_sub_feeds, normalize_article_link, parse_results,
+        parse_sources, parse_sub_feed, raw_xml_by_url, reading_stats, record_parsed_validators,
+        remove_unchanged_sources, resolve_duplicate_guids, sort_articles_newest_first,
+        split_author_name, trim_to_feed_document, validate_fetch_result, HTML_NOT_FEED_MESSAGE,
+        PARSE_DEADLINE_MESSAGE,
+    };
+    use crate::cache::{ValidatorStore, ValidatorUpdate};
+    use crate::types::{
+        CacheValidators, DuplicateGuidPolicy, Enclosure, FetchResult, ParseOptions, ParsedArticle,
+        PreviousSourceStat, RawFeed, SourceRequest, SourceStats,
+    };
+
+    /// A successfully fetched `body` for `source`.
+    fn feed_result(source: &str, url: &str, body: &str) -> FetchResult {
+        FetchResult::Success(RawFeed {
+            source_name: source.to_string(),
+            url: url.to_string(),
+            xml: body.to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// A fetched RSS 2.0 feed for `source` whose channel holds `items`.
+    fn rss_result(source: &str, url: &str, items: &str) -> FetchResult {
+        let xml = format!(
+            "<rss version=\"2.0\" xmlns:content=\"http://purl.org/rss/1.0/modules/content/\" \
+             xmlns:media=\"http://search.yahoo.com/mrss/\"><channel>{items}</channel></rss>"
+        );
+        feed_result(source, url, &xml)
+    }
+
+    /// Parses a source's sub-feeds one after another and merges them, as
+    /// `parse_results` does for each source.
+    fn parse_source_group(
+        source_name: &str,
+        results: &[FetchResult],
+        options: &ParseOptions,
+        deadline: Option<std::time::Instant>,
+    ) -> (Vec<ParsedArticle>, SourceStats) {
+        let parsed = results
+            .iter()
+            .map(|result| parse_sub_feed(source_name, result, options, deadline))
+            .collect();
+        merge_sub_feeds(source_name, results, parsed, options)
+    }
 
     #[test]
     fn extracts_dc_creator_authors_from_rss_items() {
@@ -836,4 +2378,1473 @@ mod tests {
             "<rss><channel><item><title>One</title></item></channel></rss>"
         );
     }
+
+    #[test]
+    fn counts_only_articles_missing_from_previous_ids() {
+        let article = |guid: &str| ParsedArticle {
+            link: format!("https://example.com/{guid}"),
+            guid: Some(guid.to_string()),
+            ..Default::default()
+        };
+        let articles = vec![article("a"), article("b"), article("c")];
+        let seen: HashSet<String> = ["a".to_string()].into_iter().collect();
+
+        assert_eq!(count_new_articles(&articles, &seen), 2);
+    }
+
+    #[test]
+    fn extracts_event_namespace_fields() {
+        let xml = r#"
+        <rss xmlns:ev="http://purl.org/rss/1.0/modules/event/"><channel>
+          <item>
+            <title>Jazz Night</title>
+            <link>https://example.com/jazz</link>
+            <ev:startdate>2026-05-01T20:00:00Z</ev:startdate>
+            <ev:enddate>2026-05-01T23:00:00Z</ev:enddate>
+            <ev:location>Blue Room</ev:location>
+          </item>
+        </channel></rss>
+        "#;
+
+        let items = extract_rss_item_metadata(xml);
+        let event = items[0].event.clone().expect("event details");
+
+        assert_eq!(event.name.as_deref(), Some("Jazz Night"));
+        assert_eq!(event.start.as_deref(), Some("2026-05-01T20:00:00Z"));
+        assert_eq!(event.end.as_deref(), Some("2026-05-01T23:00:00Z"));
+        assert_eq!(event.location.as_deref(), Some("Blue Room"));
+    }
+
+    #[test]
+    fn groups_articles_by_source_newest_first() {
+        let article = |source: &str, title: &str, published: &str| ParsedArticle {
+            title: title.to_string(),
+            source: source.to_string(),
+            published: published.to_string(),
+            ..Default::default()
+        };
+        let articles = vec![
+            article("A", "old", "2026-01-01T10:00:00+00:00"),
+            article("B", "only", "2026-01-02T10:00:00+00:00"),
+            article("A", "new", "2026-01-01T12:00:00+01:00"),
+            article("A", "newest", "2026-01-01T11:30:00Z"),
+        ];
+
+        let grouped = group_articles_by_source(&articles);
+        let titles: Vec<&str> = grouped["A"].iter().map(|a| a.title.as_str()).collect();
+
+        assert_eq!(titles, vec!["newest", "new", "old"]);
+        assert_eq!(grouped["B"].len(), 1);
+    }
+
+    #[test]
+    fn normalizes_published_dates_to_utc() {
+        let items = r#"
+          <item>
+            <title>Morning edition</title>
+            <link>https://example.jp/morning</link>
+            <pubDate>Tue, 05 Mar 2024 09:30:00 +0900</pubDate>
+          </item>
+          <item>
+            <title>Undated</title>
+            <link>https://example.jp/undated</link>
+          </item>"#;
+        let results = vec![rss_result("Tokyo", "https://example.jp/rss", items)];
+        let options = ParseOptions {
+            normalize_dates_utc: true,
+            ..Default::default()
+        };
+
+        let (articles, _) = parse_source_group("Tokyo", &results, &options, None);
+
+        assert_eq!(articles[0].published, "2024-03-05T00:30:00Z");
+        assert!(articles[1].published.ends_with('Z'));
+        assert!(chrono::DateTime::parse_from_rfc3339(&articles[1].published).is_ok());
+    }
+
+    #[test]
+    fn collects_every_category_once() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+          <title>Tags</title>
+          <entry>
+            <title>Tagged</title>
+            <id>tagged-1</id>
+            <link href="https://example.com/tagged"/>
+            <category term="tech" label="Technology"/>
+            <category term="rust"/>
+            <category term="TECHNOLOGY"/>
+            <category term=" "/>
+            <category term="Rust"/>
+            <category term="open-source"/>
+            <updated>2024-05-01T10:00:00Z</updated>
+          </entry>
+        </feed>"#;
+        let results = vec![feed_result("Tags", "https://example.com/atom", xml)];
+
+        let (articles, _) = parse_source_group("Tags", &results, &ParseOptions::default(), None);
+
+        assert_eq!(articles[0].category.as_deref(), Some("Technology"));
+        assert_eq!(
+            articles[0].categories,
+            vec!["Technology", "rust", "open-source"]
+        );
+    }
+
+    #[test]
+    fn infers_category_from_feed_url_path() {
+        assert_eq!(
+            infer_category_from_feed_url("https://example.com/rss/technology.xml").as_deref(),
+            Some("Technology")
+        );
+        assert_eq!(
+            infer_category_from_feed_url("https://example.com/news/world-politics/rss").as_deref(),
+            Some("World Politics")
+        );
+        assert_eq!(
+            infer_category_from_feed_url("https://example.com/feed/"),
+            None
+        );
+    }
+
+    #[test]
+    fn skips_sub_feeds_once_parse_deadline_passes() {
+        let item = "<item><title>One</title><link>https://example.com/1</link></item>";
+        let results = vec![
+            rss_result("Example", "https://example.com/feed", item),
+            rss_result("Example", "https://example.com/other", item),
+        ];
+
+        let (articles, stat) = parse_source_group(
+            "Example",
+            &results,
+            &ParseOptions::default(),
+            Some(std::time::Instant::now()),
+        );
+
+        assert!(articles.is_empty());
+        assert!(stat.parse_timeout);
+        assert_eq!(stat.status, "warning");
+        let sub_feeds = stat.sub_feeds.expect("sub-feed stats");
+        assert!(sub_feeds.iter().all(|sub| sub.status == "skipped"
+            && sub.error_message.as_deref() == Some(PARSE_DEADLINE_MESSAGE)));
+        assert!(stat
+            .error_message
+            .is_some_and(|message| message.starts_with(PARSE_DEADLINE_MESSAGE)));
+    }
+
+    #[test]
+    fn blocks_links_on_blocked_domains_and_subdomains() {
+        let blocked: HashSet<String> = ["example.com".to_string()].into_iter().collect();
+
+        assert!(is_blocked_link("https://example.com/story", &blocked));
+        assert!(is_blocked_link("https://www.Example.com/story", &blocked));
+        assert!(is_blocked_link("https://news.example.com./story", &blocked));
+        assert!(!is_blocked_link("https://notexample.com/story", &blocked));
+        assert!(!is_blocked_link("not a url", &blocked));
+    }
+
+    #[test]
+    fn estimates_publish_interval_from_median_gap() {
+        let at = |value: &str| {
+            chrono::DateTime::parse_from_rfc3339(value)
+                .expect("valid timestamp")
+                .with_timezone(&chrono::Utc)
+        };
+        let times = vec![
+            at("2026-01-01T12:00:00Z"),
+            at("2026-01-01T10:00:00Z"),
+            at("2026-01-01T11:00:00Z"),
+            at("2026-01-02T11:00:00Z"),
+        ];
+
+        assert_eq!(median_publish_interval_minutes(times), Some(60.0));
+        assert_eq!(
+            median_publish_interval_minutes(vec![at("2026-01-01T10:00:00Z")]),
+            None
+        );
+    }
+
+    #[test]
+    fn keeps_raw_xml_only_for_opted_in_sources() {
+        let feed = |source: &str, url: &str| {
+            feed_result(source, url, &format!("<rss><!-- {url} --></rss>"))
+        };
+        let results = vec![
+            feed("Podcast", "https://example.com/podcast.xml"),
+            feed("News", "https://example.com/news.xml"),
+            feed("Podcast", "https://example.com/extras.xml"),
+        ];
+        let sources = HashSet::from(["Podcast".to_string()]);
+
+        let raw_xml = raw_xml_by_url(&results, &sources);
+
+        assert_eq!(raw_xml.len(), 2);
+        assert_eq!(
+            raw_xml["https://example.com/extras.xml"],
+            "<rss><!-- https://example.com/extras.xml --></rss>"
+        );
+        assert!(!raw_xml.contains_key("https://example.com/news.xml"));
+    }
+
+    #[test]
+    fn removes_sources_whose_status_and_count_are_unchanged() {
+        let stat = |name: &str, status: &str, article_count: usize| SourceStats {
+            name: name.to_string(),
+            status: status.to_string(),
+            article_count,
+            ..Default::default()
+        };
+        let prior = |status: &str, article_count: usize| PreviousSourceStat {
+            status: status.to_string(),
+            article_count,
+        };
+        let mut stats: BTreeMap<String, SourceStats> = [
+            stat("Alpha", "success", 10),
+            stat("Beta", "success", 12),
+            stat("Gamma", "warning", 0),
+            stat("Delta", "success", 4),
+        ]
+        .into_iter()
+        .map(|stat| (stat.name.clone(), stat))
+        .collect();
+        let previous: HashMap<String, PreviousSourceStat> = [
+            ("Alpha".to_string(), prior("success", 10)),
+            ("Beta".to_string(), prior("success", 9)),
+            ("Gamma".to_string(), prior("success", 0)),
+        ]
+        .into_iter()
+        .collect();
+
+        let unchanged = remove_unchanged_sources(&mut stats, &previous);
+
+        assert_eq!(unchanged, vec!["Alpha".to_string()]);
+        let mut remaining: Vec<&str> = stats.keys().map(String::as_str).collect();
+        remaining.sort();
+        assert_eq!(remaining, vec!["Beta", "Delta", "Gamma"]);
+    }
+
+    #[test]
+    fn flags_empty_feeds_with_error_like_titles() {
+        let parse = |xml: &str| feed_rs::parser::parse(xml.as_bytes()).expect("valid feed");
+        let rate_limited = parse(
+            r#"<rss version="2.0"><channel><title>Error: Instance has been rate limited</title><description>Try again later</description></channel></rss>"#,
+        );
+        let quiet = parse(
+            r#"<rss version="2.0"><channel><title>Local Council Notices</title></channel></rss>"#,
+        );
+        let with_items = parse(
+            r#"<rss version="2.0"><channel><title>Error Correction Weekly</title><item><title>Codes</title><link>https://example.com/codes</link></item></channel></rss>"#,
+        );
+
+        assert!(looks_like_service_error(&rate_limited));
+        assert!(!looks_like_service_error(&quiet));
+        assert!(!looks_like_service_error(&with_items));
+    }
+
+    #[test]
+    fn applies_duplicate_guid_policy_within_a_sub_feed() {
+        let article = |guid: &str, link: &str| ParsedArticle {
+            guid: Some(guid.to_string()),
+            link: link.to_string(),
+            ..Default::default()
+        };
+        let feed = vec![
+            article("post", "https://example.com/a"),
+            article("post", "https://example.com/b"),
+            article("other", "https://example.com/c"),
+            article("post", "https://example.com/d"),
+        ];
+
+        let mut linked = feed.clone();
+        assert_eq!(
+            resolve_duplicate_guids(&mut linked, DuplicateGuidPolicy::FallbackToLink),
+            2
+        );
+        let guids: Vec<_> = linked
+            .iter()
+            .map(|article| article.guid.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(
+            guids,
+            vec![
+                "post",
+                "https://example.com/b",
+                "other",
+                "https://example.com/d"
+            ]
+        );
+
+        let mut flagged = feed;
+        assert_eq!(
+            resolve_duplicate_guids(&mut flagged, DuplicateGuidPolicy::Flag),
+            2
+        );
+        let flags: Vec<_> = flagged
+            .iter()
+            .map(|article| article.duplicate_guid)
+            .collect();
+        assert_eq!(flags, vec![false, true, false, true]);
+        assert_eq!(flagged[1].guid.as_deref(), Some("post"));
+    }
+
+    #[test]
+    fn sort_keys_order_by_publish_time_then_id() {
+        let article = |published: &str, guid: &str| {
+            let mut article = ParsedArticle {
+                published: published.to_string(),
+                guid: Some(guid.to_string()),
+                ..Default::default()
+            };
+            article.sort_key = article_sort_key(&article);
+            article
+        };
+        let early = article("2026-01-01T09:00:00+00:00", "z");
+        let tie_a = article("2026-01-01T10:00:00+00:00", "a");
+        let tie_b = article("2026-01-01T12:00:00+02:00", "b");
+        let late = article("2026-01-01T10:00:00.001+00:00", "a");
+
+        assert_eq!(tie_a.sort_key, "001767261600000:a");
+        assert!(early.sort_key < tie_a.sort_key);
+        assert!(tie_a.sort_key < tie_b.sort_key);
+        assert!(tie_b.sort_key < late.sort_key);
+    }
+
+    #[test]
+    fn undated_entries_keep_the_same_sort_key_across_runs() {
+        let results = vec![rss_result(
+            "Example",
+            "https://example.com/feed.xml",
+            "<item><title>Undated</title><link>https://example.com/undated</link></item>",
+        )];
+        let options = ParseOptions::default();
+
+        let (first, _) = parse_source_group("Example", &results, &options, None);
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let (second, _) = parse_source_group("Example", &results, &options, None);
+
+        assert!(first[0].published_synthesized);
+        assert_eq!(
+            first[0].sort_key,
+            "000000000000000:https://example.com/undated"
+        );
+        assert_eq!(first[0].sort_key, second[0].sort_key);
+    }
+
+    #[test]
+    fn content_hash_ignores_whitespace_but_tracks_edits() {
+        let article = |title: &str, description: &str| ParsedArticle {
+            title: title.to_string(),
+            link: "https://example.com/story".to_string(),
+            description: description.to_string(),
+            ..Default::default()
+        };
+        let original = content_hash(&article("Budget passes", "The vote was close."));
+        let reflowed = content_hash(&article(" Budget  passes", "The vote\nwas close. "));
+        let edited = content_hash(&article("Budget passes", "The vote was not close."));
+
+        assert_eq!(original, "1fc788b9f5bc9ec9");
+        assert_eq!(original, reflowed);
+        assert_ne!(original, edited);
+    }
+
+    #[test]
+    fn parses_json_feed_items() {
+        let json = r#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Indie Blog",
+            "items": [
+                {
+                    "id": "post-1",
+                    "url": "https://blog.example.com/post-1",
+                    "title": "Shipping notes",
+                    "content_html": "<p>Why we dropped </feed> parsing hacks</p>",
+                    "date_published": "2026-02-03T08:00:00Z",
+                    "image": "https://blog.example.com/post-1.png",
+                    "tags": ["Engineering", "Rust"]
+                },
+                {
+                    "id": "post-2",
+                    "url": "https://blog.example.com/post-2",
+                    "title": "Plain text only",
+                    "content_text": "Just words."
+                }
+            ]
+        }"#;
+        let results = vec![feed_result(
+            "Indie",
+            "https://blog.example.com/feed.json",
+            json,
+        )];
+
+        let (articles, stat) =
+            parse_source_group("Indie", &results, &ParseOptions::default(), None);
+
+        assert_eq!(stat.status, "success");
+        assert_eq!(articles.len(), 2);
+        let first = &articles[0];
+        assert_eq!(first.title, "Shipping notes");
+        assert_eq!(first.link, "https://blog.example.com/post-1");
+        assert_eq!(first.description, "Why we dropped parsing hacks");
+        assert_eq!(first.published, "2026-02-03T08:00:00+00:00");
+        assert_eq!(
+            first.image.as_deref(),
+            Some("https://blog.example.com/post-1.png")
+        );
+        assert_eq!(first.category.as_deref(), Some("Engineering"));
+        assert_eq!(first.categories, vec!["Engineering", "Rust"]);
+        assert_eq!(articles[1].description, "Just words.");
+    }
+
+    #[test]
+    fn normalizes_links_for_deduplication() {
+        let params: Vec<String> = ["utm_*", "fbclid"].iter().map(|p| p.to_string()).collect();
+
+        assert_eq!(
+            normalize_article_link(
+                "HTTPS://News.Example.com/story/?utm_source=rss&fbclid=x",
+                &params
+            ),
+            "https://news.example.com/story"
+        );
+        assert_eq!(
+            normalize_article_link("https://example.com/story?id=7&UTM_medium=feed", &params),
+            "https://example.com/story?id=7"
+        );
+    }
+
+    #[test]
+    fn strips_tracking_params_from_article_links() {
+        let items = r#"
+          <item>
+            <title>Story</title>
+            <link>https://example.com/story?utm_medium=rss&amp;id=5&amp;fbclid=abc#comments</link>
+          </item>
+          <item>
+            <title>Plain</title>
+            <link>https://example.com/plain?ref=home</link>
+          </item>"#;
+        let results = vec![rss_result("Tracked", "https://example.com/rss", items)];
+        let parse = |options: &ParseOptions| {
+            let (articles, _) = parse_source_group("Tracked", &results, options, None);
+            articles.into_iter().map(|a| a.link).collect::<Vec<_>>()
+        };
+
+        let stripped = parse(&ParseOptions {
+            strip_tracking_params: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            stripped,
+            vec![
+                "https://example.com/story?id=5#comments",
+                "https://example.com/plain?ref=home",
+            ]
+        );
+
+        let custom = parse(&ParseOptions {
+            strip_tracking_params: true,
+            tracking_params: Some(vec!["ref".to_string()]),
+            ..Default::default()
+        });
+        assert_eq!(
+            custom,
+            vec![
+                "https://example.com/story?utm_medium=rss&id=5&fbclid=abc#comments",
+                "https://example.com/plain",
+            ]
+        );
+
+        assert_eq!(
+            parse(&ParseOptions::default())[0],
+            "https://example.com/story?utm_medium=rss&id=5&fbclid=abc#comments"
+        );
+    }
+
+    #[test]
+    fn dedupes_across_sources_keeping_the_first() {
+        let article = |link: &str| ParsedArticle {
+            link: link.to_string(),
+            ..Default::default()
+        };
+        let stat = |name: &str, article_count: usize| SourceStats {
+            name: name.to_string(),
+            article_count,
+            ..Default::default()
+        };
+        let mut groups = vec![
+            (
+                vec![
+                    article("https://example.com/a"),
+                    article("https://example.com/b/"),
+                ],
+                stat("First", 2),
+            ),
+            (
+                vec![
+                    article("https://example.com/b?utm_campaign=x"),
+                    article("https://example.com/c"),
+                ],
+                stat("Second", 2),
+            ),
+        ];
+
+        dedupe_articles(&mut groups, &["utm_*".to_string()]);
+
+        assert_eq!(groups[0].1.article_count, 2);
+        assert_eq!(groups[1].1.article_count, 1);
+        assert_eq!(groups[1].0[0].link, "https://example.com/c");
+    }
+
+    #[test]
+    fn caps_articles_per_source_keeping_the_newest() {
+        let item = |n: u32| {
+            format!(
+                "<item><title>Story {n}</title><link>https://example.com/{n}</link>\
+                 <pubDate>Thu, 0{n} Jan 2026 10:00:00 GMT</pubDate></item>"
+            )
+        };
+        let items = [2, 5, 1, 4, 3].map(item).concat();
+        let results = vec![rss_result("Example", "https://example.com/rss", &items)];
+        let options = ParseOptions {
+            max_articles_per_source: Some(2),
+            ..Default::default()
+        };
+
+        let (articles, stat) = parse_source_group("Example", &results, &options, None);
+
+        let titles: Vec<&str> = articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, vec!["Story 5", "Story 4"]);
+        assert_eq!(stat.article_count, 5);
+        assert_eq!(stat.returned_count, 2);
+    }
+
+    #[test]
+    fn keeps_every_atom_author_and_falls_back_to_email() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+          <title>Example</title>
+          <entry>
+            <title>Co-written</title>
+            <link href="https://example.com/co-written"/>
+            <id>urn:example:1</id>
+            <updated>2026-01-01T00:00:00Z</updated>
+            <author><name>Ada Lovelace</name></author>
+            <author><name></name><email>desk@example.com</email></author>
+            <author><name>Charles Babbage</name></author>
+          </entry>
+        </feed>"#;
+        let results = vec![feed_result("Example", "https://example.com/atom", xml)];
+
+        let (articles, _) = parse_source_group("Example", &results, &ParseOptions::default(), None);
+
+        assert_eq!(
+            articles[0].authors,
+            vec!["Ada Lovelace", "desk@example.com", "Charles Babbage"]
+        );
+    }
+
+    #[test]
+    fn flags_teaser_descriptions_as_likely_truncated() {
+        let phrases: Vec<String> = super::DEFAULT_TRUNCATION_PHRASES
+            .iter()
+            .map(|phrase| phrase.to_string())
+            .collect();
+        let title = "Council approves the new transit budget";
+        let check = |raw: &str| looks_truncated(title, &clean_html(raw), raw, &phrases);
+
+        assert!(check(
+            "<p>The vote followed months of debate. Continue reading \u{2192}</p>"
+        ));
+        assert!(check("The vote followed months of debate [&hellip;]"));
+        assert!(check("Budget passes"));
+        assert!(check(
+            r#"<p>The vote followed months of debate.</p><p><a href="https://example.com/budget">The full story</a></p>"#
+        ));
+        assert!(!check(
+            "The council voted 7-2 on Tuesday to approve the budget, which adds two bus \
+             lines and extends evening service across the city."
+        ));
+        assert!(!check(
+            r#"The vote followed <a href="https://example.com/council">the council</a> debate over two new bus lines."#
+        ));
+        assert!(!check(""));
+    }
+
+    #[test]
+    fn truncation_phrases_replace_the_defaults() {
+        let results = vec![rss_result(
+            "Example",
+            "https://example.com/feed",
+            r#"
+              <item><title>Budget</title><link>https://example.com/1</link>
+                <description>The council voted on the transit budget. Weiterlesen</description></item>
+              <item><title>Storm</title><link>https://example.com/2</link>
+                <description>Coastal towns prepare for flooding. Read more</description></item>"#,
+        )];
+        let options = ParseOptions {
+            truncation_phrases: Some(vec![" Weiterlesen ".to_string()]),
+            ..Default::default()
+        };
+
+        let (articles, _) = parse_source_group("Example", &results, &options, None);
+
+        let flags: Vec<bool> = articles.iter().map(|a| a.likely_truncated).collect();
+        assert_eq!(flags, vec![true, false]);
+    }
+
+    #[test]
+    fn reading_stats_round_up_to_whole_minutes() {
+        assert_eq!(reading_stats(""), (0, 0));
+        assert_eq!(reading_stats("  one\ntwo  three "), (3, 1));
+        assert_eq!(reading_stats(&"word ".repeat(200)), (200, 1));
+        assert_eq!(reading_stats(&"word ".repeat(201)), (201, 2));
+    }
+
+    #[test]
+    fn sorts_articles_newest_first_across_offsets() {
+        let article = |title: &str, published: &str| ParsedArticle {
+            title: title.to_string(),
+            published: published.to_string(),
+            ..Default::default()
+        };
+        let undated = ParsedArticle {
+            published_synthesized: true,
+            ..article("undated", "2026-06-01T00:00:00Z")
+        };
+        let mut articles = vec![
+            article("morning", "2026-01-01T08:00:00Z"),
+            undated,
+            article("offset", "2026-01-01T11:00:00+02:00"),
+            article("unparseable", "not a date"),
+            article("noon", "2026-01-01T12:00:00Z"),
+        ];
+
+        sort_articles_newest_first(&mut articles);
+
+        let titles: Vec<&str> = articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec!["noon", "offset", "morning", "undated", "unparseable"]
+        );
+    }
+
+    #[test]
+    fn collects_every_image_with_the_lead_image_first() {
+        let items = r#"
+            <item>
+              <title>Photos</title>
+              <link>https://example.com/photos</link>
+              <enclosure url="https://cdn.example.com/lead.png" length="10" type="image/png"/>
+              <media:content url="https://cdn.example.com/second.jpg" medium="image"/>
+              <media:content url="https://cdn.example.com/third.webp?w=800"/>
+              <media:content url="https://cdn.example.com/lead.png"/>
+              <media:content url="https://cdn.example.com/clip.mp4" type="video/mp4"/>
+            </item>"#;
+        let results = vec![rss_result("Gallery", "https://example.com/rss", items)];
+
+        let (articles, _) = parse_source_group("Gallery", &results, &ParseOptions::default(), None);
+
+        assert_eq!(
+            articles[0].image.as_deref(),
+            Some("https://cdn.example.com/lead.png")
+        );
+        assert_eq!(
+            articles[0].images,
+            vec![
+                "https://cdn.example.com/lead.png",
+                "https://cdn.example.com/second.jpg",
+                "https://cdn.example.com/third.webp?w=800",
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_relative_image_urls_against_the_article_link() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+          <title>Relative</title>
+          <entry>
+            <title>Story</title>
+            <id>story-1</id>
+            <link href="https://news.example.com/world/story-1"/>
+            <link rel="enclosure" type="image/jpeg" href="/images/foo.jpg"/>
+            <link rel="enclosure" type="image/png" href="//cdn.example.com/bar.png"/>
+            <link rel="enclosure" type="image/gif" href="https://img.example.com/baz.gif"/>
+            <updated>2024-05-01T10:00:00Z</updated>
+          </entry>
+        </feed>"#;
+        let results = vec![feed_result("Relative", "https://example.com/atom", xml)];
+
+        let (articles, _) =
+            parse_source_group("Relative", &results, &ParseOptions::default(), None);
+
+        assert_eq!(
+            articles[0].image.as_deref(),
+            Some("https://news.example.com/images/foo.jpg")
+        );
+        assert_eq!(
+            articles[0].images,
+            vec![
+                "https://news.example.com/images/foo.jpg",
+                "https://cdn.example.com/bar.png",
+                "https://img.example.com/baz.gif",
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_non_image_enclosures() {
+        let items = r#"
+            <item>
+              <title>Episode 1</title>
+              <link>https://example.com/ep1</link>
+              <enclosure url="https://cdn.example.com/ep1.mp3" length="12345" type="audio/mpeg"/>
+              <media:content url="https://cdn.example.com/cover.jpg"/>
+            </item>"#;
+        let results = vec![rss_result("Podcast", "https://example.com/rss", items)];
+
+        let (articles, _) = parse_source_group("Podcast", &results, &ParseOptions::default(), None);
+
+        assert_eq!(
+            articles[0].enclosures,
+            vec![Enclosure {
+                url: "https://cdn.example.com/ep1.mp3".to_string(),
+                mime_type: Some("audio/mpeg".to_string()),
+                length_bytes: Some(12345),
+            }]
+        );
+        assert_eq!(
+            articles[0].image.as_deref(),
+            Some("https://cdn.example.com/cover.jpg")
+        );
+        assert_eq!(
+            articles[0].images,
+            vec!["https://cdn.example.com/cover.jpg"]
+        );
+    }
+
+    #[test]
+    fn lead_image_skips_audio_and_video_media() {
+        let items = r#"
+            <item>
+              <title>Clip</title>
+              <link>https://example.com/clip</link>
+              <media:content url="https://cdn.example.com/clip.mp4" type="video/mp4"/>
+              <media:content url="https://cdn.example.com/poster.png" type="image/png"/>
+            </item>
+            <item>
+              <title>Audio only</title>
+              <link>https://example.com/audio</link>
+              <enclosure url="https://cdn.example.com/show.mp3" length="1" type="audio/mpeg"/>
+            </item>
+            <item>
+              <title>Untyped</title>
+              <link>https://example.com/untyped</link>
+              <media:content url="https://cdn.example.com/episode.m4a"/>
+              <media:content url="https://cdn.example.com/render?id=42"/>
+            </item>"#;
+        let results = vec![rss_result("Video", "https://example.com/rss", items)];
+
+        let (articles, _) = parse_source_group("Video", &results, &ParseOptions::default(), None);
+
+        assert_eq!(
+            articles[0].image.as_deref(),
+            Some("https://cdn.example.com/poster.png")
+        );
+        assert_eq!(articles[1].image, None);
+        assert_eq!(
+            articles[1].enclosures[0].url,
+            "https://cdn.example.com/show.mp3"
+        );
+        assert_eq!(
+            articles[2].image.as_deref(),
+            Some("https://cdn.example.com/render?id=42")
+        );
+        assert_eq!(
+            articles[2].enclosures[0].url,
+            "https://cdn.example.com/episode.m4a"
+        );
+    }
+
+    #[test]
+    fn drops_entries_older_than_the_cutoff_but_keeps_undated_ones() {
+        let items = r#"
+          <item><title>Old</title><link>https://example.com/old</link>
+            <pubDate>Wed, 31 Dec 2025 23:00:00 GMT</pubDate></item>
+          <item><title>New</title><link>https://example.com/new</link>
+            <pubDate>Thu, 01 Jan 2026 01:00:00 +0100</pubDate></item>
+          <item><title>Undated</title><link>https://example.com/undated</link></item>"#;
+        let results = vec![rss_result("Example", "https://example.com/rss", items)];
+        let options = ParseOptions {
+            since: chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+                .ok()
+                .map(|since| since.to_utc()),
+            ..Default::default()
+        };
+
+        let (articles, stat) = parse_source_group("Example", &results, &options, None);
+
+        let titles: Vec<&str> = articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, vec!["New", "Undated"]);
+        assert_eq!(stat.filtered_out_count, 1);
+        assert_eq!(stat.article_count, 2);
+    }
+
+    #[test]
+    fn uses_rss_guid_as_the_dedupe_key() {
+        let items = r#"
+          <item><title>Live blog</title><link>https://example.com/live</link>
+            <guid isPermaLink="false">example-live-2026-10-15</guid></item>
+          <item><title>Live blog, day two</title><link>https://example.com/live</link>
+            <guid isPermaLink="false">example-live-2026-10-16</guid></item>
+          <item><title>No guid</title><link>https://example.com/plain</link></item>"#;
+        let results = vec![rss_result("Example", "https://example.com/rss", items)];
+
+        let (articles, stat) =
+            parse_source_group("Example", &results, &ParseOptions::default(), None);
+        let guids: Vec<_> = articles.iter().map(|a| a.guid.as_deref()).collect();
+        assert_eq!(
+            guids,
+            vec![
+                Some("example-live-2026-10-15"),
+                Some("example-live-2026-10-16"),
+                Some("https://example.com/plain"),
+            ]
+        );
+
+        let repost = |source: &str, guid: &str| ParsedArticle {
+            source: source.to_string(),
+            link: "https://example.com/live?utm_source=feed".to_string(),
+            guid: Some(guid.to_string()),
+            ..Default::default()
+        };
+        let mut groups = vec![
+            (articles, stat),
+            (
+                vec![
+                    repost("Example", "example-live-2026-10-16"),
+                    repost("Example", "https://example.com/plain/?utm_source=x"),
+                ],
+                SourceStats::default(),
+            ),
+        ];
+        dedupe_articles(&mut groups, &["utm_*".to_string()]);
+
+        assert_eq!(groups[0].0.len(), 3);
+        assert!(groups[1].0.is_empty());
+    }
+
+    #[test]
+    fn matches_non_url_guids_only_within_their_source() {
+        let article = |source: &str, guid: &str| ParsedArticle {
+            source: source.to_string(),
+            link: format!("https://{source}.example/{guid}"),
+            guid: Some(guid.to_string()),
+            ..Default::default()
+        };
+        let mut groups = vec![
+            (
+                vec![
+                    article("alpha", "1"),
+                    article("alpha", "https://wire.example/story"),
+                ],
+                SourceStats::default(),
+            ),
+            (
+                vec![
+                    article("beta", "1"),
+                    article("beta", "2"),
+                    article("beta", "https://wire.example/story"),
+                ],
+                SourceStats::default(),
+            ),
+        ];
+
+        dedupe_articles(&mut groups, &[]);
+
+        let guids: Vec<_> = groups[1].0.iter().map(|a| a.guid.as_deref()).collect();
+        assert_eq!(guids, vec![Some("1"), Some("2")]);
+        assert_eq!(groups[0].0.len(), 2);
+    }
+
+    #[test]
+    fn merges_sub_feeds_keeping_the_first_occurrence() {
+        let feed = |url: &str, items: &str| rss_result("Example", url, items);
+        let results = vec![
+            feed(
+                "https://example.com/world.rss",
+                r#"<item><title>Summit</title><link>https://example.com/summit</link></item>
+                   <item><title>Storm</title><link>https://example.com/storm</link></item>"#,
+            ),
+            feed(
+                "https://example.com/top.rss",
+                r#"<item><title>Summit (top)</title><link>https://example.com/summit?utm_medium=rss</link></item>
+                   <item><title>Budget</title><link>https://example.com/budget</link></item>"#,
+            ),
+        ];
+        let options = ParseOptions {
+            dedupe_within_source: true,
+            ..Default::default()
+        };
+
+        let (articles, stat) = parse_source_group("Example", &results, &options, None);
+
+        let titles: Vec<&str> = articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, vec!["Summit", "Storm", "Budget"]);
+        assert_eq!(stat.article_count, 3);
+        assert_eq!(stat.merged_duplicate_count, 1);
+        let sub_counts: Vec<usize> = stat
+            .sub_feeds
+            .expect("sub-feed stats")
+            .iter()
+            .map(|sub| sub.article_count)
+            .collect();
+        assert_eq!(sub_counts, vec![2, 2]);
+    }
+
+    #[test]
+    fn warns_when_no_article_carries_a_publish_date() {
+        let feed = |url: &str, items: &str| rss_result("Example", url, items);
+        let undated = feed(
+            "https://example.com/undated.rss",
+            r#"<item><title>One</title><link>https://example.com/1</link></item>
+               <item><title>Two</title><link>https://example.com/2</link></item>"#,
+        );
+        let dated = feed(
+            "https://example.com/dated.rss",
+            r#"<item><title>Three</title><link>https://example.com/3</link>
+                 <pubDate>Tue, 06 Jan 2026 10:00:00 GMT</pubDate></item>"#,
+        );
+        let options = ParseOptions::default();
+
+        let (articles, stat) =
+            parse_source_group("Example", std::slice::from_ref(&undated), &options, None);
+        assert!(articles.iter().all(|article| article.published_synthesized));
+        assert!(stat.dates_synthesized);
+        assert_eq!(stat.status, "warning");
+        assert_eq!(
+            stat.error_message.as_deref(),
+            Some("feed provides no publish dates")
+        );
+
+        let (_, stat) = parse_source_group("Example", &[undated, dated], &options, None);
+        assert!(!stat.dates_synthesized);
+        assert_eq!(stat.status, "success");
+        assert_eq!(stat.error_message, None);
+    }
+
+    #[test]
+    fn validates_feed_formats_and_rejects_html_pages() {
+        let body = |xml: &str| feed_result("Example", "https://example.com/feed", xml);
+
+        let rss = validate_fetch_result(&body(
+            r#"<rss version="2.0"><channel><title>World &amp; Politics</title>
+                 <item><title>One</title><link>https://example.com/1</link></item>
+                 <item><title>Two</title><link>https://example.com/2</link></item>
+               </channel></rss>"#,
+        ));
+        assert!(rss.ok);
+        assert_eq!(rss.feed_type.as_deref(), Some("rss"));
+        assert_eq!(rss.feed_title.as_deref(), Some("World & Politics"));
+        assert_eq!(rss.article_count, 2);
+
+        let atom = validate_fetch_result(&body(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom"><title>Atom</title></feed>"#,
+        ));
+        assert_eq!(atom.feed_type.as_deref(), Some("atom"));
+        assert_eq!(atom.article_count, 0);
+
+        let json = validate_fetch_result(&body(
+            r#"{"version": "https://jsonfeed.org/version/1.1", "title": "JSON",
+                "items": [{"id": "1", "url": "https://example.com/1", "title": "One"}]}"#,
+        ));
+        assert_eq!(json.feed_type.as_deref(), Some("json"));
+        assert_eq!(json.article_count, 1);
+
+        let page = validate_fetch_result(&body(
+            "<!DOCTYPE html><html><head><title>Home</title></head><body></body></html>",
+        ));
+        assert!(!page.ok);
+        assert_eq!(page.feed_type.as_deref(), Some("html"));
+        assert!(page.error.is_some());
+
+        let garbage = validate_fetch_result(&body("not a feed"));
+        assert!(!garbage.ok);
+        assert_eq!(garbage.feed_type, None);
+    }
+
+    #[test]
+    fn regroups_sub_feeds_parsed_in_parallel_by_source() {
+        let feed = |source: &str, url: &str, links: &[&str]| {
+            let items: String = links
+                .iter()
+                .map(|link| format!("<item><title>{link}</title><link>{link}</link></item>"))
+                .collect();
+            rss_result(source, url, &items)
+        };
+        // Completion order interleaves the sources and reverses their feeds.
+        let results = vec![
+            feed("Beta", "https://beta.test/b.rss", &["https://beta.test/3"]),
+            feed(
+                "Alpha",
+                "https://alpha.test/b.rss",
+                &["https://alpha.test/2", "https://alpha.test/3"],
+            ),
+            feed("Beta", "https://beta.test/a.rss", &["https://beta.test/1"]),
+            feed(
+                "Alpha",
+                "https://alpha.test/a.rss",
+                &["https://alpha.test/1", "https://alpha.test/2"],
+            ),
+        ];
+        let sources = vec![
+            SourceRequest {
+                name: "Alpha".to_string(),
+                urls: vec![
+                    "https://alpha.test/a.rss".to_string(),
+                    "https://alpha.test/b.rss".to_string(),
+                ],
+            },
+            SourceRequest {
+                name: "Beta".to_string(),
+                urls: vec![
+                    "https://beta.test/a.rss".to_string(),
+                    "https://beta.test/b.rss".to_string(),
+                ],
+            },
+        ];
+        let options = ParseOptions {
+            dedupe_within_source: true,
+            ..Default::default()
+        };
+
+        let (articles, stats) = parse_results(results, sources, &options);
+
+        let links = |source: &str| -> Vec<&str> {
+            articles
+                .iter()
+                .filter(|a| a.source == source)
+                .map(|a| a.link.as_str())
+                .collect()
+        };
+        assert_eq!(
+            links("Alpha"),
+            vec![
+                "https://alpha.test/1",
+                "https://alpha.test/2",
+                "https://alpha.test/3"
+            ]
+        );
+        assert_eq!(
+            links("Beta"),
+            vec!["https://beta.test/1", "https://beta.test/3"]
+        );
+        assert_eq!(stats["Alpha"].article_count, 3);
+        assert_eq!(stats["Alpha"].merged_duplicate_count, 1);
+        assert_eq!(stats["Beta"].article_count, 2);
+        let sub_urls: Vec<String> = stats["Beta"]
+            .sub_feeds
+            .as_ref()
+            .expect("sub-feed stats")
+            .iter()
+            .map(|sub| sub.url.clone())
+            .collect();
+        assert_eq!(
+            sub_urls,
+            vec!["https://beta.test/a.rss", "https://beta.test/b.rss"]
+        );
+    }
+
+    #[test]
+    fn orders_output_by_request_regardless_of_fetch_completion() {
+        let feed = |source: &str, url: &str, link: &str| {
+            rss_result(
+                source,
+                url,
+                &format!("<item><title>{link}</title><link>{link}</link></item>"),
+            )
+        };
+        let sources = vec![
+            SourceRequest {
+                name: "Zeta".to_string(),
+                urls: vec![
+                    "https://zeta.test/a.rss".to_string(),
+                    "https://zeta.test/b.rss".to_string(),
+                ],
+            },
+            SourceRequest {
+                name: "Alpha".to_string(),
+                urls: vec!["https://alpha.test/a.rss".to_string()],
+            },
+        ];
+        let completions = [
+            vec![
+                feed("Alpha", "https://alpha.test/a.rss", "https://alpha.test/1"),
+                feed("Zeta", "https://zeta.test/b.rss", "https://zeta.test/2"),
+                feed("Zeta", "https://zeta.test/a.rss", "https://zeta.test/1"),
+            ],
+            vec![
+                feed("Zeta", "https://zeta.test/a.rss", "https://zeta.test/1"),
+                feed("Zeta", "https://zeta.test/b.rss", "https://zeta.test/2"),
+                feed("Alpha", "https://alpha.test/a.rss", "https://alpha.test/1"),
+            ],
+        ];
+
+        for results in completions {
+            let (articles, stats) =
+                parse_results(results, sources.clone(), &ParseOptions::default());
+
+            let links: Vec<&str> = articles.iter().map(|a| a.link.as_str()).collect();
+            assert_eq!(
+                links,
+                vec![
+                    "https://zeta.test/1",
+                    "https://zeta.test/2",
+                    "https://alpha.test/1"
+                ]
+            );
+            assert_eq!(stats.keys().collect::<Vec<_>>(), vec!["Alpha", "Zeta"]);
+            let sub_urls: Vec<&str> = stats["Zeta"]
+                .sub_feeds
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(|sub| sub.url.as_str())
+                .collect();
+            assert_eq!(
+                sub_urls,
+                vec!["https://zeta.test/a.rss", "https://zeta.test/b.rss"]
+            );
+        }
+    }
+
+    #[test]
+    fn parses_supplied_feed_bodies_without_fetching() {
+        let rss = |link: &str| {
+            format!(
+                r#"<rss version="2.0"><channel><title>Example</title><item><title>{link}</title><link>{link}</link><pubDate>Tue, 06 Jan 2026 10:00:00 GMT</pubDate></item></channel></rss>"#
+            )
+            .into_bytes()
+        };
+        let sources = vec![
+            SourceRequest {
+                name: "Example".to_string(),
+                urls: vec![
+                    "https://example.invalid/a.rss".to_string(),
+                    "https://example.invalid/b.rss".to_string(),
+                ],
+            },
+            SourceRequest {
+                name: "Broken".to_string(),
+                urls: vec!["https://broken.invalid/feed".to_string()],
+            },
+        ];
+        let options = ParseOptions {
+            feed_bodies: HashMap::from([
+                (
+                    "https://example.invalid/a.rss".to_string(),
+                    rss("https://example.com/1"),
+                ),
+                (
+                    "https://example.invalid/b.rss".to_string(),
+                    rss("https://example.com/2"),
+                ),
+                (
+                    "https://broken.invalid/feed".to_string(),
+                    b"\x1f\x8b not really gzip".to_vec(),
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        let result = tokio::runtime::Runtime::new()
+            .expect("test runtime")
+            .block_on(parse_sources(
+                sources,
+                4,
+                Duration::from_millis(1),
+                &options,
+            ));
+
+        let links: Vec<&str> = result.articles.iter().map(|a| a.link.as_str()).collect();
+        assert_eq!(
+            links,
+            vec!["https://example.com/1", "https://example.com/2"]
+        );
+        assert_eq!(result.metrics.fetch_attempts, 0);
+        assert_eq!(result.metrics.fetch_duration_ms, 0);
+        assert_eq!(result.source_stats["Example"].status, "success");
+        let broken = &result.source_stats["Broken"];
+        assert_eq!(broken.status, "warning");
+        assert_eq!(broken.error_kinds, vec!["parse"]);
+    }
+
+    #[test]
+    fn reports_the_slowest_sub_feed_as_the_source_fetch_time() {
+        let feed = |url: &str, duration_ms: u128| match rss_result("Example", url, "") {
+            FetchResult::Success(raw) => FetchResult::Success(RawFeed { duration_ms, ..raw }),
+            other => other,
+        };
+        let results = vec![
+            feed("https://example.com/world.xml", 120),
+            feed("https://example.com/sport.xml", 450),
+        ];
+
+        let (_, stat) = parse_source_group("Example", &results, &ParseOptions::default(), None);
+
+        assert_eq!(stat.fetch_duration_ms, 450);
+    }
+
+    #[test]
+    fn takes_feed_title_and_description_from_the_first_sub_feed_declaring_them() {
+        let feed = |url: &str, xml: &str| feed_result("Example", url, xml);
+        let results = vec![
+            feed(
+                "https://example.com/world.xml",
+                r#"<rss version="2.0"><channel><title> </title></channel></rss>"#,
+            ),
+            feed(
+                "https://example.com/sport.xml",
+                r#"<rss version="2.0"><channel><title>Example &amp; Co Sport</title>
+                  <description>Scores and results</description></channel></rss>"#,
+            ),
+            feed(
+                "https://example.com/arts.xml",
+                r#"<rss version="2.0"><channel><title>Example Arts</title>
+                  <description>Reviews</description></channel></rss>"#,
+            ),
+        ];
+
+        let (_, stat) = parse_source_group("Example", &results, &ParseOptions::default(), None);
+
+        assert_eq!(stat.feed_title.as_deref(), Some("Example & Co Sport"));
+        assert_eq!(stat.feed_description.as_deref(), Some("Scores and results"));
+    }
+
+    #[test]
+    fn reports_the_latest_build_date_and_shortest_ttl() {
+        let feed = |url: &str, channel: &str| rss_result("Example", url, channel);
+        let results = vec![
+            feed(
+                "https://example.com/world.xml",
+                "<lastBuildDate>Thu, 15 Oct 2026 09:00:00 GMT</lastBuildDate><ttl>60</ttl>",
+            ),
+            feed(
+                "https://example.com/sport.xml",
+                "<lastBuildDate>Fri, 16 Oct 2026 07:30:00 +0200</lastBuildDate><ttl>15</ttl>",
+            ),
+            feed("https://example.com/arts.xml", ""),
+        ];
+
+        let (_, stat) = parse_source_group("Example", &results, &ParseOptions::default(), None);
+
+        assert_eq!(
+            stat.last_build_date.as_deref(),
+            Some("2026-10-16T05:30:00+00:00")
+        );
+        assert_eq!(stat.ttl_minutes, Some(15));
+
+        let (_, stat) =
+            parse_source_group("Example", &results[2..], &ParseOptions::default(), None);
+        assert_eq!(stat.last_build_date, None);
+        assert_eq!(stat.ttl_minutes, None);
+    }
+
+    #[test]
+    fn suggests_polling_after_the_most_conservative_cache_hint() {
+        let now = chrono::Utc::now();
+        let feed = |url: &str, ttl: &str, fresh_until: Option<chrono::DateTime<chrono::Utc>>| {
+            match rss_result("Example", url, ttl) {
+                FetchResult::Success(raw) => FetchResult::Success(RawFeed { fresh_until, ..raw }),
+                other => other,
+            }
+        };
+        let cached_for_two_hours = feed(
+            "https://example.com/world.xml",
+            "<ttl>30</ttl>",
+            Some(now + chrono::Duration::hours(2)),
+        );
+        let ttl_only = feed("https://example.com/sport.xml", "<ttl>60</ttl>", None);
+        let poll_after = |text: Option<&str>| {
+            chrono::DateTime::parse_from_rfc3339(text.expect("poll hint"))
+                .expect("RFC 3339")
+                .to_utc()
+        };
+        let options = ParseOptions::default();
+
+        let (_, stat) =
+            parse_source_group("Example", &[cached_for_two_hours, ttl_only], &options, None);
+
+        let sub_feeds = stat.sub_feeds.as_deref().unwrap_or_default();
+        assert_eq!(
+            poll_after(sub_feeds[0].suggested_poll_after.as_deref()),
+            now + chrono::Duration::hours(2)
+        );
+        let ttl_hint = poll_after(sub_feeds[1].suggested_poll_after.as_deref());
+        assert!(ttl_hint >= now + chrono::Duration::minutes(60));
+        assert!(ttl_hint < now + chrono::Duration::minutes(61));
+        assert_eq!(poll_after(stat.suggested_poll_after.as_deref()), ttl_hint);
+
+        let (_, stat) = parse_source_group(
+            "Example",
+            &[feed("https://example.com/arts.xml", "", None)],
+            &options,
+            None,
+        );
+        assert_eq!(stat.suggested_poll_after, None);
+    }
+
+    #[test]
+    fn keeps_cached_validators_until_a_feed_parses() {
+        let etag = |value: &str| CacheValidators {
+            etag: Some(value.to_string()),
+            last_modified: None,
+        };
+        let with_etag = |result: FetchResult, value: &str| match result {
+            FetchResult::Success(raw) => FetchResult::Success(RawFeed {
+                validators: etag(value),
+                ..raw
+            }),
+            other => other,
+        };
+        let good_url = "https://example.com/good.xml";
+        let broken_url = "https://example.com/broken.xml";
+        let store = ValidatorStore::default();
+        store.record([good_url, broken_url].map(|url| ValidatorUpdate::Fresh {
+            url: url.to_string(),
+            validators: etag("\"v1\""),
+        }));
+        let results = vec![
+            with_etag(rss_result("Example", good_url, ""), "\"v2\""),
+            with_etag(feed_result("Example", broken_url, "<rss><chan"), "\"v2\""),
+        ];
+        let updates = results
+            .iter()
+            .filter_map(ValidatorUpdate::from_result)
+            .collect();
+        let sources = vec![SourceRequest {
+            name: "Example".to_string(),
+            urls: vec![good_url.to_string(), broken_url.to_string()],
+        }];
+
+        let (_, stats) = parse_results(results, sources, &ParseOptions::default());
+        record_parsed_validators(&store, updates, &stats);
+
+        let validators = store.validators();
+        assert_eq!(validators[good_url], etag("\"v2\""));
+        assert_eq!(validators[broken_url], etag("\"v1\""));
+    }
+
+    #[test]
+    fn prefers_content_encoded_body_when_asked() {
+        let items = r#"
+            <item><title>Long read</title><link>https://example.com/long</link>
+              <description>Short teaser.</description>
+              <content:encoded><![CDATA[<p>The whole story, every paragraph.</p>]]></content:encoded>
+            </item>
+            <item><title>Teaser only</title><link>https://example.com/teaser</link>
+              <description>Only a teaser.</description>
+            </item>"#;
+        let results = vec![rss_result("Example", "https://example.com/rss", items)];
+        let descriptions = |prefer_full_content: bool| {
+            let options = ParseOptions {
+                prefer_full_content,
+                ..Default::default()
+            };
+            let (articles, _) = parse_source_group("Example", &results, &options, None);
+            articles
+                .into_iter()
+                .map(|article| article.description)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(descriptions(false), vec!["Short teaser.", "Only a teaser."]);
+        assert_eq!(
+            descriptions(true),
+            vec!["The whole story, every paragraph.", "Only a teaser."]
+        );
+    }
+
+    #[test]
+    fn extracts_content_encoded_as_content_text() {
+        let items = r#"
+            <item><title>Long read</title><link>https://example.com/long</link>
+              <description>Short teaser.</description>
+              <content:encoded><![CDATA[<p>The whole <b>story</b> here.</p><p>Every paragraph.</p>]]></content:encoded>
+            </item>
+            <item><title>Teaser only</title><link>https://example.com/teaser</link>
+              <description>Only a teaser.</description>
+            </item>"#;
+        let results = vec![rss_result("Example", "https://example.com/rss", items)];
+        let content_texts = |extract_full_content: bool| {
+            let options = ParseOptions {
+                extract_full_content,
+                ..Default::default()
+            };
+            let (articles, _) = parse_source_group("Example", &results, &options, None);
+            articles
+                .into_iter()
+                .map(|article| (article.description, article.content_text))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            content_texts(true),
+            vec![
+                (
+                    "Short teaser.".to_string(),
+                    Some("The whole story here. Every paragraph.".to_string())
+                ),
+                ("Only a teaser.".to_string(), None),
+            ]
+        );
+        assert!(content_texts(false)
+            .iter()
+            .all(|(_, content_text)| content_text.is_none()));
+    }
+
+    #[test]
+    fn html_responses_get_an_autodiscovery_hint() {
+        let page = |content_type: Option<&str>, body: &str| match feed_result(
+            "Example",
+            "https://example.com/news",
+            body,
+        ) {
+            FetchResult::Success(raw) => FetchResult::Success(RawFeed {
+                content_type: content_type.map(str::to_string),
+                ..raw
+            }),
+            other => other,
+        };
+        let options = ParseOptions::default();
+
+        let served_as_html = page(
+            Some("Text/HTML; charset=utf-8"),
+            "<div><p>Latest news</p></div>",
+        );
+        let (articles, stat) = parse_source_group(
+            "Example",
+            std::slice::from_ref(&served_as_html),
+            &options,
+            None,
+        );
+        assert!(articles.is_empty());
+        assert_eq!(stat.error_message.as_deref(), Some(HTML_NOT_FEED_MESSAGE));
+        let sub_feeds = stat.sub_feeds.unwrap_or_default();
+        assert_eq!(
+            sub_feeds[0].content_type.as_deref(),
+            Some("Text/HTML; charset=utf-8")
+        );
+
+        let sniffed = page(
+            Some("application/xml"),
+            "<!DOCTYPE html><html><body>Home</body></html>",
+        );
+        let (_, stat) = parse_source_group("Example", &[sniffed], &options, None);
+        assert_eq!(stat.error_message.as_deref(), Some(HTML_NOT_FEED_MESSAGE));
+
+        let broken_xml = page(Some("application/rss+xml"), "<rss><channel><item>");
+        let (_, stat) = parse_source_group("Example", &[broken_xml], &options, None);
+        assert!(stat
+            .error_message
+            .is_some_and(|message| message.starts_with("Parse error: ")));
+    }
 }
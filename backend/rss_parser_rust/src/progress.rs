@@ -0,0 +1,132 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use pyo3::prelude::*;
+
+/// Running totals shared between a parse run and its observers.
+///
+/// The fetch phase bumps `feeds_done` and `bytes_fetched` as each request
+/// finishes, and the parse phase adds to `articles_parsed` per sub-feed.
+#[derive(Debug, Default)]
+pub struct ProgressCounters {
+    feeds_done: AtomicUsize,
+    articles_parsed: AtomicUsize,
+    bytes_fetched: AtomicU64,
+}
+
+impl ProgressCounters {
+    /// Records one finished feed request, successful or not.
+    pub fn feed_done(&self) {
+        self.feeds_done.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Adds parsed articles to the running total.
+    pub fn add_articles(&self, count: usize) {
+        self.articles_parsed.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Adds response body bytes to the running total.
+    pub fn add_bytes(&self, count: usize) {
+        self.bytes_fetched
+            .fetch_add(count as u64, Ordering::Relaxed);
+    }
+}
+
+type SourceDoneFn = dyn Fn(&str, &str, usize) + Send + Sync;
+
+/// Observer told about each source as soon as all of its feed requests have
+/// finished, with `(source_name, status, sources_done)`.
+///
+/// The third argument counts finished sources rather than articles so far:
+/// articles are parsed only after every fetch has ended, so no running
+/// article count exists while sources complete. Callers wanting article
+/// progress poll `IngestProgress.articles_parsed` or read the returned
+/// `source_stats`.
+#[derive(Clone)]
+pub struct SourceProgressCallback {
+    callback: Arc<SourceDoneFn>,
+}
+
+impl SourceProgressCallback {
+    /// Wraps a Rust closure taking `(source_name, status, sources_done)`.
+    pub fn new(callback: impl Fn(&str, &str, usize) + Send + Sync + 'static) -> Self {
+        Self {
+            callback: Arc::new(callback),
+        }
+    }
+
+    /// Wraps a Python callable. The GIL is taken for each call, and an
+    /// exception raised by the callable is reported as unraisable rather
+    /// than aborting the run.
+    pub fn from_python(callback: Py<PyAny>) -> Self {
+        Self::new(move |source_name, status, sources_done| {
+            Python::with_gil(|py| {
+                if let Err(err) = callback.call1(py, (source_name, status, sources_done)) {
+                    err.write_unraisable_bound(py, Some(callback.bind(py)));
+                }
+            });
+        })
+    }
+
+    /// Reports that `source_name` finished fetching.
+    pub fn source_done(&self, source_name: &str, status: &str, sources_done: usize) {
+        (self.callback)(source_name, status, sources_done);
+    }
+}
+
+impl std::fmt::Debug for SourceProgressCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SourceProgressCallback")
+    }
+}
+
+/// Live progress handle for `parse_feeds_parallel`.
+///
+/// Create one in Python, pass it as `progress=`, and poll its getters from
+/// another thread while the run is in flight. Counters accumulate across
+/// runs until `reset` is called.
+#[pyclass(name = "IngestProgress")]
+#[derive(Clone, Default)]
+pub struct IngestProgress {
+    counters: Arc<ProgressCounters>,
+}
+
+impl IngestProgress {
+    /// Shared counters to thread into a parse run.
+    pub fn counters(&self) -> Arc<ProgressCounters> {
+        self.counters.clone()
+    }
+}
+
+#[pymethods]
+impl IngestProgress {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed requests finished so far.
+    #[getter]
+    fn feeds_done(&self) -> usize {
+        self.counters.feeds_done.load(Ordering::Relaxed)
+    }
+
+    /// Articles parsed so far.
+    #[getter]
+    fn articles_parsed(&self) -> usize {
+        self.counters.articles_parsed.load(Ordering::Relaxed)
+    }
+
+    /// Response body bytes fetched so far.
+    #[getter]
+    fn bytes_fetched(&self) -> u64 {
+        self.counters.bytes_fetched.load(Ordering::Relaxed)
+    }
+
+    /// Zeroes every counter.
+    fn reset(&self) {
+        self.counters.feeds_done.store(0, Ordering::Relaxed);
+        self.counters.articles_parsed.store(0, Ordering::Relaxed);
+        self.counters.bytes_fetched.store(0, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+/// Caller-supplied word lists for lexicon-based sentiment scoring. Words are
+/// stored lowercased.
+#[derive(Clone, Debug, Default)]
+pub struct SentimentLexicon {
+    /// Words that count toward a positive score.
+    pub positive: HashSet<String>,
+    /// Words that count toward a negative score.
+    pub negative: HashSet<String>,
+}
+
+impl SentimentLexicon {
+    /// Builds a lexicon from raw word lists, lowercasing and dropping blanks.
+    pub fn new(positive: Vec<String>, negative: Vec<String>) -> Self {
+        let normalize = |words: Vec<String>| {
+            words
+                .into_iter()
+                .map(|word| word.trim().to_lowercase())
+                .filter(|word| !word.is_empty())
+                .collect()
+        };
+        Self {
+            positive: normalize(positive),
+            negative: normalize(negative),
+        }
+    }
+
+    /// Scores text in `[-1.0, 1.0]` as `(positive - negative) / matched`
+    /// over lexicon word hits. Text with no hits scores `0.0`.
+    pub fn score(&self, text: &str) -> f32 {
+        let lower = text.to_lowercase();
+        let (mut positive, mut negative) = (0u32, 0u32);
+        for token in lower.split(|c: char| !c.is_alphanumeric() && c != '\'') {
+            let token = token.trim_matches('\'');
+            if self.positive.contains(token) {
+                positive += 1;
+            } else if self.negative.contains(token) {
+                negative += 1;
+            }
+        }
+
+        let matched = positive + negative;
+        if matched == 0 {
+            return 0.0;
+        }
+        (positive as f32 - negative as f32) / matched as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SentimentLexicon;
+
+    #[test]
+    fn scores_title_against_lexicon() {
+        let lexicon = SentimentLexicon::new(
+            vec!["Gain".to_string(), "record".to_string()],
+            vec!["crash".to_string(), " ".to_string()],
+        );
+
+        assert_eq!(lexicon.score("Markets gain, record highs"), 1.0);
+        assert_eq!(lexicon.score("Crash follows record gain"), 1.0 / 3.0);
+        assert_eq!(lexicon.score("Nothing to see here"), 0.0);
+    }
+}
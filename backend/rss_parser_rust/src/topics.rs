@@ -525,7 +525,7 @@ mod tests {
             },
             ArticleInput {
                 article_id: 4,
-                title: "Climate scientists warn about global warming impact".into(),
+                title: "Climate scientists warn rising temperatures threaten global health".into(),
                 order_index: 3,
             },
         ];
@@ -1,9 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
 
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 use serde::{Deserialize, Serialize};
 
+use crate::cache::ValidatorStore;
+use crate::keywords::KeywordFilter;
+use crate::progress::{ProgressCounters, SourceProgressCallback};
+use crate::sentiment::SentimentLexicon;
+
 /// Describes a named news source and the list of RSS/Atom feed URLs to fetch
 /// from it.
 #[derive(Clone, Debug, Deserialize)]
@@ -14,8 +21,269 @@ pub struct SourceRequest {
     pub urls: Vec<String>,
 }
 
+/// Optional per-call behavior for a fetch-and-parse run.
+///
+/// Every field defaults to the historical behavior, so callers only set the
+/// knobs they need.
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    /// Article IDs seen on the previous run, keyed by feed URL. Sub-feeds with
+    /// an entry here report [`SubFeedStat::new_article_count`].
+    pub previous_article_ids: HashMap<String, HashSet<String>>,
+    /// Remove control, zero-width, and bidi format characters from titles.
+    pub strip_decorative_chars: bool,
+    /// Also remove emoji from titles when `strip_decorative_chars` is set.
+    pub strip_emoji: bool,
+    /// Extract structured event details for event/calendar feeds.
+    pub extract_events: bool,
+    /// User-Agent strings rotated per host across requests. Empty keeps the
+    /// client's single fixed User-Agent.
+    pub user_agents: Vec<String>,
+    /// Extra headers sent with every feed request, replacing the rotated or
+    /// default value for any header they share.
+    pub request_headers: reqwest::header::HeaderMap,
+    /// Populate [`ParsedArticle::content_text`] from the entry content body.
+    pub extract_full_content: bool,
+    /// Build descriptions from the full content body (`content:encoded`)
+    /// when present, instead of the summary.
+    pub prefer_full_content: bool,
+    /// Also return articles grouped by source in
+    /// [`ParseResult::articles_by_source`].
+    pub group_by_source: bool,
+    /// Names of sources whose fetched feed bodies are also returned in
+    /// [`ParseResult::raw_xml`]. Other sources' bodies are dropped after
+    /// parsing as usual.
+    pub raw_xml_sources: HashSet<String>,
+    /// Feed bodies the caller already downloaded, keyed by feed URL. Those
+    /// URLs are decoded and parsed from these bytes instead of being fetched.
+    pub feed_bodies: HashMap<String, Vec<u8>>,
+    /// Unpack ZIP responses holding a single XML entry before parsing.
+    pub unzip_archives: bool,
+    /// Seed for the random jitter in fetch timing, making it reproducible.
+    /// `None` seeds from system entropy.
+    pub random_seed: Option<u64>,
+    /// Read `file://` and `data:` feed URLs locally. Off by default so a
+    /// caller-supplied URL cannot read files from the machine's disk.
+    pub allow_local_urls: bool,
+    /// Derive a category from the feed URL path when an entry has none.
+    pub infer_category_from_url: bool,
+    /// Upper bound on wall-clock time spent in the parse phase. It is checked
+    /// before each sub-feed and each entry; parsing one feed document is not
+    /// interrupted, so the phase can overrun by that document's parse time.
+    pub parse_deadline: Option<Duration>,
+    /// Lowercased domains whose articles are dropped during extraction. A
+    /// domain also blocks its subdomains.
+    pub blocked_domains: HashSet<String>,
+    /// Source stats from the previous run. When set, only sources whose status
+    /// or article count changed are returned, and the rest are listed in
+    /// [`ParseResult::unchanged_sources`].
+    pub previous_source_stats: Option<HashMap<String, PreviousSourceStat>>,
+    /// Live counters updated as feeds are fetched and parsed.
+    pub progress: Option<Arc<ProgressCounters>>,
+    /// Called as each source's last feed request finishes.
+    pub source_progress: Option<SourceProgressCallback>,
+    /// Stops the run early when cancelled; feeds fetched by then are still
+    /// parsed.
+    pub cancel: Option<tokio_util::sync::CancellationToken>,
+    /// HTTP statuses read as a feed body even though they are errors by
+    /// default (any non-2xx status).
+    pub accept_statuses: HashSet<u16>,
+    /// Score each article's title and description against
+    /// `sentiment_lexicon` into [`ParsedArticle::sentiment_score`].
+    pub compute_sentiment: bool,
+    /// Word lists used when `compute_sentiment` is set.
+    pub sentiment_lexicon: SentimentLexicon,
+    /// Flag empty feeds whose title or description looks like an error
+    /// message via [`SubFeedStat::service_error_suspect`].
+    pub detect_service_errors: bool,
+    /// How to treat entries that reuse a guid already seen in the same
+    /// sub-feed.
+    pub duplicate_guid_policy: DuplicateGuidPolicy,
+    /// Download each source's feed icon and inline it as a data URI in
+    /// [`SourceStats::feed_icon_data`].
+    pub inline_icons: bool,
+    /// Validators from the previous run keyed by feed URL. Matching requests
+    /// are sent conditionally and may come back as
+    /// [`FetchResult::NotModified`].
+    pub cache_validators: HashMap<String, CacheValidators>,
+    /// Cache updated in place with the validators and `304` streaks of this
+    /// run's responses.
+    pub feed_cache: Option<Arc<ValidatorStore>>,
+    /// Credentials for private feeds keyed by feed URL.
+    pub credentials: HashMap<String, FeedCredentials>,
+    /// Most requests allowed in flight to any single host at once, on top
+    /// of the global concurrency limit. `None` leaves hosts unlimited.
+    pub per_host_max: Option<usize>,
+    /// Treat the run's `max_concurrent` as a ceiling and let an AIMD
+    /// controller grow or shrink the concurrency from observed latency and
+    /// overload errors.
+    pub adaptive_concurrency: bool,
+    /// Most redirects followed per feed request before failing it. `None`
+    /// allows ten, like a default `reqwest` client.
+    pub max_redirects: Option<usize>,
+    /// Extra attempts for connection errors, timeouts, and 5xx responses,
+    /// skipped when the wait before one would exceed the request timeout.
+    pub max_retries: u32,
+    /// Delay before the first retry; each later retry doubles it, plus
+    /// random jitter.
+    pub retry_base_delay: Duration,
+    /// Drop articles whose guid, or normalized link when there is none, was
+    /// already returned earlier in the run, across all sources.
+    pub dedupe: bool,
+    /// Drop entries that an earlier sub-feed of the same source already
+    /// returned, keyed like `dedupe`, so a source's `article_count` counts
+    /// unique stories. Sub-feed counts stay pre-merge.
+    pub dedupe_within_source: bool,
+    /// Query parameters stripped when normalizing links for `dedupe` and by
+    /// `strip_tracking_params`; a trailing `*` matches a prefix. `None` uses
+    /// the built-in tracking list.
+    pub tracking_params: Option<Vec<String>>,
+    /// Remove tracking query parameters from each returned article link,
+    /// keeping other parameters and the fragment.
+    pub strip_tracking_params: bool,
+    /// Keep paragraph breaks in descriptions instead of flattening them.
+    pub preserve_paragraphs: bool,
+    /// Leave ideographic, thin, and narrow no-break spaces in titles and
+    /// text descriptions as written instead of collapsing them.
+    pub keep_ideographic_spaces: bool,
+    /// Fill [`ParsedArticle::summary`] with the plain-text description cut
+    /// to at most this many characters.
+    pub summary_max_chars: Option<usize>,
+    /// Description endings that set [`ParsedArticle::likely_truncated`],
+    /// matched case-insensitively. `None` uses the built-in list.
+    pub truncation_phrases: Option<Vec<String>>,
+    /// Sort the combined article list newest first by publish time.
+    pub sort_by_date: bool,
+    /// Format `published` as UTC with a `Z` suffix instead of `+00:00`.
+    pub normalize_dates_utc: bool,
+    /// Keep only this many of each source's newest articles.
+    pub max_articles_per_source: Option<usize>,
+    /// Caller-supplied pooled client; the process-wide client when `None`.
+    pub client: Option<reqwest::Client>,
+    /// Drop entries published before this instant. Undated entries are kept.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Include/exclude keywords an article's title or description must pass.
+    pub keyword_filter: KeywordFilter,
+    /// Whether descriptions are plain text, lightweight markdown, or
+    /// sanitized HTML.
+    pub description_format: DescriptionFormat,
+}
+
+/// HTTP cache validators for conditional feed requests.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CacheValidators {
+    /// `ETag` response header, sent back as `If-None-Match`.
+    pub etag: Option<String>,
+    /// `Last-Modified` response header, sent back as `If-Modified-Since`.
+    pub last_modified: Option<String>,
+}
+
+/// HTTP credentials for a private feed. `Debug` output never includes the
+/// secret.
+#[derive(Clone, PartialEq, Eq)]
+pub enum FeedCredentials {
+    /// HTTP Basic auth.
+    Basic {
+        /// User name.
+        username: String,
+        /// Password.
+        password: String,
+    },
+    /// `Authorization: Bearer <token>`.
+    Bearer(String),
+}
+
+impl FeedCredentials {
+    /// Builds credentials from the Python-facing dict: `username` and
+    /// `password` for Basic auth, or `token` for a bearer token. The error
+    /// names the expected keys but never echoes the values.
+    pub fn from_fields(fields: &HashMap<String, String>) -> Result<Self, String> {
+        let field = |name: &str| fields.get(name).cloned();
+        match (field("username"), field("password"), field("token")) {
+            (Some(username), Some(password), None) => Ok(Self::Basic { username, password }),
+            (None, None, Some(token)) => Ok(Self::Bearer(token)),
+            _ => Err("expected either \"username\" and \"password\", or \"token\"".to_string()),
+        }
+    }
+}
+
+impl std::fmt::Debug for FeedCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .finish_non_exhaustive(),
+            Self::Bearer(_) => f.write_str("Bearer(..)"),
+        }
+    }
+}
+
+/// Output format for article descriptions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DescriptionFormat {
+    /// Tags stripped, entities decoded, whitespace flattened.
+    #[default]
+    Text,
+    /// Links, emphasis, list items, and paragraph breaks kept as markdown.
+    Markdown,
+    /// A safe HTML subset for inline rendering; see
+    /// [`crate::cleaner::sanitize_html`].
+    SafeHtml,
+}
+
+impl DescriptionFormat {
+    /// Parses the Python-facing format name (`"text"`, `"markdown"`, or
+    /// `"safe_html"`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "markdown" => Some(Self::Markdown),
+            "safe_html" => Some(Self::SafeHtml),
+            _ => None,
+        }
+    }
+}
+
+/// Handling for entries whose guid repeats an earlier entry in the same
+/// sub-feed. Duplicates are counted in [`SubFeedStat::duplicate_guid_count`]
+/// under every policy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DuplicateGuidPolicy {
+    /// Leave duplicate guids untouched.
+    #[default]
+    Ignore,
+    /// Keep every entry, replacing a repeated guid with the entry's link.
+    FallbackToLink,
+    /// Keep the guid on the first entry and mark later ones with
+    /// [`ParsedArticle::duplicate_guid`].
+    Flag,
+}
+
+impl DuplicateGuidPolicy {
+    /// Parses the Python-facing policy name (`"ignore"`, `"link"`, or
+    /// `"flag"`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "ignore" => Some(Self::Ignore),
+            "link" => Some(Self::FallbackToLink),
+            "flag" => Some(Self::Flag),
+            _ => None,
+        }
+    }
+}
+
+/// The parts of a previous run's [`SourceStats`] compared for delta output.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PreviousSourceStat {
+    /// Previous overall status.
+    pub status: String,
+    /// Previous article count.
+    pub article_count: usize,
+}
+
 /// Raw response body for a single feed URL that was successfully fetched.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct RawFeed {
     /// Name of the source this feed belongs to.
     pub source_name: String,
@@ -23,8 +291,52 @@ pub struct RawFeed {
     pub url: String,
     /// Raw XML body of the feed response.
     pub xml: String,
+    /// Character encoding the body was decoded from (e.g. `windows-1252`).
+    pub charset: Option<String>,
+    /// `Content-Type` of the response, or the media type of a `data:` URL.
+    pub content_type: Option<String>,
+    /// Wall-clock time spent fetching this URL.
+    pub duration_ms: u128,
+    /// Size of the response body in bytes, after any transfer decompression.
+    pub body_bytes: Option<usize>,
+    /// Every URL the request was redirected to, in order; the last one is
+    /// `final_url`. Empty when the feed answered directly.
+    pub redirects: Vec<String>,
+    /// HTTP protocol version the response was served over (e.g. `HTTP/2.0`).
+    pub http_version: Option<String>,
+    /// HTTP status code of the final response.
+    pub status_code: Option<u16>,
+    /// URL of the final response after following redirects.
+    pub final_url: Option<String>,
+    /// Cache validators returned with the response, for the next run.
+    pub validators: CacheValidators,
+    /// When the response stops being fresh according to its
+    /// `Cache-Control` or `Expires` header.
+    pub fresh_until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A feed URL whose server answered a conditional request with
+/// `304 Not Modified`.
+#[derive(Clone, Debug, Default)]
+pub struct NotModifiedFeed {
+    /// Name of the source this feed belongs to.
+    pub source_name: String,
+    /// Exact URL that was fetched.
+    pub url: String,
     /// Wall-clock time spent fetching this URL.
     pub duration_ms: u128,
+    /// HTTP protocol version the response was served over.
+    pub http_version: Option<String>,
+    /// HTTP status code of the final response.
+    pub status_code: Option<u16>,
+    /// URL of the final response after following redirects.
+    pub final_url: Option<String>,
+    /// Validators to reuse next run: any refreshed by the 304 response,
+    /// otherwise the ones that were sent.
+    pub validators: CacheValidators,
+    /// When the 304 response stops being fresh according to its
+    /// `Cache-Control` or `Expires` header.
+    pub fresh_until: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 /// Describes a fetch failure for a single feed URL.
@@ -36,10 +348,26 @@ pub struct FetchError {
     pub url: String,
     /// Human-readable error description.
     pub message: String,
+    /// Failure category for grouping: `"timeout"`, `"connect"`, `"dns"`,
+    /// `"tls"`, `"http_4xx"`, `"http_5xx"`, `"parse"` (an undecodable body),
+    /// or `"other"`.
+    pub error_kind: String,
     /// Wall-clock time spent before the request failed.
     pub duration_ms: u128,
     /// Whether the HTTP client classified this failure as a timeout.
     pub timed_out: bool,
+    /// Size of the response body in bytes, when it was read before the
+    /// failure (e.g. an undecodable body).
+    pub body_bytes: Option<usize>,
+    /// HTTP protocol version of the response, when one was received.
+    pub http_version: Option<String>,
+    /// HTTP status code of the response, when one was received.
+    pub status_code: Option<u16>,
+    /// URL of the final response after redirects, when one was received.
+    pub final_url: Option<String>,
+    /// Delay the server asked for with `Retry-After` on a 429 or 503
+    /// response, in seconds.
+    pub retry_after_secs: Option<u64>,
 }
 
 /// Outcome of a single feed fetch operation.
@@ -49,10 +377,36 @@ pub enum FetchResult {
     Success(RawFeed),
     /// The fetch attempt failed with the enclosed error details.
     Error(FetchError),
+    /// The server reported the feed unchanged since the supplied validators.
+    NotModified(NotModifiedFeed),
+}
+
+/// Structured details for an article that describes a scheduled event.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct EventDetails {
+    /// Event name or title.
+    pub name: Option<String>,
+    /// Start date/time as published by the source.
+    pub start: Option<String>,
+    /// End date/time as published by the source.
+    pub end: Option<String>,
+    /// Venue name or address.
+    pub location: Option<String>,
+}
+
+/// A non-image media file attached to an article, such as a podcast episode.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Enclosure {
+    /// Absolute URL of the media file.
+    pub url: String,
+    /// Declared MIME type (e.g. `audio/mpeg`), when the feed gave one.
+    pub mime_type: Option<String>,
+    /// Declared file size in bytes, when the feed gave one.
+    pub length_bytes: Option<u64>,
 }
 
 /// Represents a single article parsed from an RSS or Atom feed entry.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct ParsedArticle {
     /// Article headline extracted from the feed item.
     pub title: String,
@@ -60,9 +414,20 @@ pub struct ParsedArticle {
     pub link: String,
     /// Cleaned article summary or description text.
     pub description: String,
+    /// The description as plain text, cut at a word boundary to
+    /// `summary_max_chars` with an ellipsis when shortened. Empty when no
+    /// limit was requested.
+    pub summary: String,
+    /// The description looks like a teaser for an article that continues on
+    /// the publisher's site, going by its ending, its length, or a lone
+    /// trailing link.
+    pub likely_truncated: bool,
     /// Publication date in RFC 3339 format, or the current time if
     /// unavailable.
     pub published: String,
+    /// The entry had no published or updated date, so `published` is the
+    /// time it was parsed.
+    pub published_synthesized: bool,
     /// Name of the news source that published this article.
     pub source: String,
     /// List of author names extracted from the feed entry.
@@ -74,6 +439,45 @@ pub struct ParsedArticle {
     pub image: Option<String>,
     /// Category or section label assigned to the article by the publisher.
     pub category: Option<String>,
+    /// Every category or tag on the entry, deduplicated case-insensitively
+    /// in feed order; `category` is always the first.
+    pub categories: Vec<String>,
+    /// Stable identifier taken from the entry `<guid>`/Atom `id`, falling back
+    /// to the article link.
+    pub guid: Option<String>,
+    /// Event details from JSON-LD `Event` blocks or `ev:` namespace fields,
+    /// populated only when event extraction is enabled.
+    pub event: Option<EventDetails>,
+    /// Cleaned plain text of the full `content:encoded`/Atom content body,
+    /// populated only when full-content extraction is enabled.
+    pub content_text: Option<String>,
+    /// Whether `category` was guessed from the feed URL path rather than
+    /// supplied by the publisher.
+    pub category_inferred: bool,
+    /// Lexicon sentiment of the cleaned title and description in
+    /// `[-1.0, 1.0]`, when sentiment scoring was requested.
+    pub sentiment_score: Option<f32>,
+    /// This entry repeated an earlier entry's guid in the same sub-feed and
+    /// was flagged under [`DuplicateGuidPolicy::Flag`].
+    pub duplicate_guid: bool,
+    /// Cursor-pagination key: the publish time in epoch milliseconds,
+    /// zero-padded to a fixed width, then the stable ID as a tiebreaker.
+    /// Sorts lexicographically in publish order.
+    pub sort_key: String,
+    /// Short hex digest of the whitespace-normalized title, link, and
+    /// description. Stable across runs, so a changed hash means the
+    /// publisher edited the entry.
+    pub content_hash: String,
+    /// Whitespace-separated words in the cleaned description.
+    pub word_count: usize,
+    /// Estimated minutes to read the description at 200 words per minute,
+    /// rounded up; zero only when there is no description.
+    pub reading_time_minutes: u32,
+    /// Audio, video, and other non-image media attached to the entry.
+    pub enclosures: Vec<Enclosure>,
+    /// Every image URL found in the entry's media and enclosures, in
+    /// document order without duplicates; `image` is always the first.
+    pub images: Vec<String>,
 }
 
 /// Per-URL statistics for a single sub-feed within a source.
@@ -81,16 +485,55 @@ pub struct ParsedArticle {
 pub struct SubFeedStat {
     /// The feed URL these statistics describe.
     pub url: String,
-    /// Status string: "success" or "error".
+    /// Status string: "success", "error", or "not_modified", or, when the
+    /// parse deadline passed, "skipped" (not parsed at all) or "partial"
+    /// (entries after the deadline were dropped).
     pub status: String,
     /// Number of articles successfully parsed from this sub-feed.
     pub article_count: usize,
     /// Error message if the sub-feed fetch or parse failed.
     pub error_message: Option<String>,
+    /// Failure category when the sub-feed failed: a [`FetchError::error_kind`]
+    /// value, `"parse"` for an unparseable feed, or `"timeout"` when the
+    /// parse deadline skipped it.
+    pub error_kind: Option<String>,
     /// Wall-clock time spent fetching this sub-feed.
     pub fetch_duration_ms: u128,
+    /// Size of the sub-feed's response body in bytes, when one was read.
+    pub body_bytes: Option<usize>,
     /// Whether this sub-feed failed because its request timed out.
     pub timed_out: bool,
+    /// Number of parsed articles whose IDs were absent from the caller's
+    /// previously-seen set, or `None` when no prior state was supplied.
+    pub new_article_count: Option<usize>,
+    /// HTTP protocol version the sub-feed was served over, when known.
+    pub http_version: Option<String>,
+    /// The feed parsed with no entries but its title or description reads
+    /// like a proxy or bridge error (e.g. a rate-limit notice).
+    pub service_error_suspect: bool,
+    /// Entries whose guid repeated an earlier entry in this sub-feed.
+    pub duplicate_guid_count: usize,
+    /// `ETag` to send on the next conditional request for this URL.
+    pub etag: Option<String>,
+    /// `Last-Modified` value to send on the next conditional request.
+    pub last_modified: Option<String>,
+    /// HTTP status code of the final response, when one was received.
+    pub status_code: Option<u16>,
+    /// URL of the final response after redirects, when one was received.
+    pub final_url: Option<String>,
+    /// URLs the fetch was redirected through, in order.
+    pub redirects: Vec<String>,
+    /// Delay in seconds the server asked for before the next request, from
+    /// `Retry-After` on a 429 or 503 response.
+    pub retry_after_secs: Option<u64>,
+    /// Character encoding the feed body was decoded from, when fetched.
+    pub charset: Option<String>,
+    /// `Content-Type` the feed was served with, when fetched.
+    pub content_type: Option<String>,
+    /// Earliest time worth polling this feed again, as RFC 3339: the later
+    /// of the response's `Cache-Control`/`Expires` freshness and the feed's
+    /// `<ttl>` counted from now.
+    pub suggested_poll_after: Option<String>,
 }
 
 /// Aggregate statistics for one news source across all of its sub-feeds.
@@ -100,13 +543,65 @@ pub struct SourceStats {
     pub name: String,
     /// Overall status: "success", "warning", or "error".
     pub status: String,
-    /// Total number of articles parsed from all sub-feeds of this source.
+    /// Total number of articles parsed from all sub-feeds of this source,
+    /// before any `max_articles_per_source` cap.
     pub article_count: usize,
+    /// Number of this source's articles actually returned, after the
+    /// `max_articles_per_source` cap.
+    pub returned_count: usize,
     /// Joined error messages from any failed sub-feeds.
     pub error_message: Option<String>,
+    /// Distinct failure categories of the failed sub-feeds, in sub-feed
+    /// order.
+    pub error_kinds: Vec<String>,
     /// Per-sub-feed breakdown, present when the source has multiple feed
     /// URLs.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_feeds: Option<Vec<SubFeedStat>>,
+    /// Whether parsing was cut short by the parse-phase deadline. Articles
+    /// parsed before the deadline are still returned.
+    pub parse_timeout: bool,
+    /// Articles dropped because their link host is on the blocklist.
+    pub blocked_article_count: usize,
+    /// Entries dropped for being published before the `since` cutoff.
+    pub filtered_out_count: usize,
+    /// Articles dropped by the include/exclude keyword filter.
+    pub keyword_filtered_count: usize,
+    /// Entries dropped because an earlier sub-feed of this source already
+    /// returned them, with `dedupe_within_source` set.
+    pub merged_duplicate_count: usize,
+    /// Median gap between consecutive dated articles in minutes, or `None`
+    /// with too few dated articles to estimate a publishing cadence.
+    pub avg_publish_interval_minutes: Option<f64>,
+    /// Absolute URL of the first icon or logo declared by one of the source's
+    /// feeds. Only collected when icon inlining was requested.
+    pub feed_icon_url: Option<String>,
+    /// The feed icon as a base64 `data:` URI, when inlining was requested
+    /// and the icon was small enough.
+    pub feed_icon_data: Option<String>,
+    /// The feed's own `<title>`, from the first sub-feed that declares a
+    /// non-empty one.
+    pub feed_title: Option<String>,
+    /// The feed's own description or subtitle, from the first sub-feed that
+    /// declares a non-empty one.
+    pub feed_description: Option<String>,
+    /// Latest `<lastBuildDate>` (or Atom `<updated>`) declared by the
+    /// source's feeds, as RFC 3339.
+    pub last_build_date: Option<String>,
+    /// Smallest `<ttl>` declared by the source's feeds: the minutes a feed
+    /// may be cached before it should be polled again.
+    pub ttl_minutes: Option<u32>,
+    /// Earliest `suggested_poll_after` among the source's feeds, so a
+    /// scheduler polling the whole source does not leave a feed stale.
+    pub suggested_poll_after: Option<String>,
+    /// Every returned article lacked a publish date and was stamped with the
+    /// parse time, so the source looks brand-new on every run.
+    pub dates_synthesized: bool,
+    /// Slowest sub-feed request for this source in milliseconds, which is
+    /// its wall-clock fetch time since sub-feeds are fetched concurrently.
+    pub fetch_duration_ms: u128,
+    /// Time spent parsing this source's sub-feeds in milliseconds.
+    pub parse_duration_ms: u128,
 }
 
 /// Timing and count metrics for a complete parse run.
@@ -121,7 +616,8 @@ pub struct RustMetrics {
     pub parse_duration_ms: u128,
     /// Total number of articles successfully parsed.
     pub articles_parsed: usize,
-    /// Total number of feed URL requests attempted.
+    /// Total number of feed URL requests attempted; supplied feed bodies
+    /// are not requests.
     pub fetch_attempts: usize,
     /// Feed URL requests that completed within two seconds.
     pub fetch_completed_within_2s: usize,
@@ -129,19 +625,59 @@ pub struct RustMetrics {
     pub fetch_completed_within_5s: usize,
     /// Feed URL requests that reached the configured timeout.
     pub fetch_timed_out: usize,
+    /// Failed feed URL requests counted by [`FetchError::error_kind`].
+    pub fetch_errors_by_kind: BTreeMap<String, usize>,
+    /// Concurrency limit in effect when the fetch phase finished: the
+    /// configured maximum, or where adaptive concurrency settled.
+    pub effective_concurrency: usize,
     /// Slowest individual feed URL request in milliseconds.
     pub fetch_max_request_ms: u128,
+    /// Articles dropped because their link host is on the blocklist.
+    pub articles_blocked: usize,
+    /// The run was cancelled before every feed was fetched.
+    pub cancelled: bool,
 }
 
 /// Top-level result of a full fetch-and-parse pipeline run.
-#[derive(Clone, Debug, Default)]
+///
+/// Serializes to the same shape [`parse_result_to_pydict`] builds.
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct ParseResult {
     /// All articles extracted from every feed.
     pub articles: Vec<ParsedArticle>,
-    /// Per-source statistics keyed by source name.
-    pub source_stats: HashMap<String, SourceStats>,
+    /// Per-source statistics keyed by source name. Sorted by name so the
+    /// returned dict and serialized output keep the same order every run.
+    pub source_stats: BTreeMap<String, SourceStats>,
     /// Timing and count metrics for the run.
     pub metrics: RustMetrics,
+    /// Articles grouped by source name, newest first within each source.
+    /// Present only when grouped output was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub articles_by_source: Option<BTreeMap<String, Vec<ParsedArticle>>>,
+    /// Names of sources omitted from `source_stats` because they match the
+    /// previous run. Present only when previous stats were supplied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unchanged_sources: Option<Vec<String>>,
+    /// Decoded feed bodies of the opted-in sources, keyed by feed URL.
+    /// Present only when raw XML was requested for some source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_xml: Option<BTreeMap<String, String>>,
+}
+
+/// Outcome of checking one feed URL with `validate_feed`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FeedValidation {
+    /// The URL answered with a parseable feed.
+    pub ok: bool,
+    /// The feed's cleaned title, when it has one.
+    pub feed_title: Option<String>,
+    /// Entries in the feed.
+    pub article_count: usize,
+    /// `"rss"`, `"atom"`, or `"json"` for a feed, `"html"` for a web page,
+    /// and `None` when the body was not recognized or never arrived.
+    pub feed_type: Option<String>,
+    /// Why the URL is not a usable feed.
+    pub error: Option<String>,
 }
 
 /// Converts a list of Python `(name, [url, ...])` tuples into validated
@@ -160,11 +696,122 @@ pub fn ensure_source_requests(raw: Vec<(String, Vec<String>)>) -> Vec<SourceRequ
         .collect()
 }
 
+/// Reads the `status` and `article_count` of each source from a previous
+/// run's `source_stats` dict. Missing keys fall back to empty defaults.
+pub fn extract_previous_source_stats(
+    raw: HashMap<String, Bound<'_, PyDict>>,
+) -> PyResult<HashMap<String, PreviousSourceStat>> {
+    raw.into_iter()
+        .map(|(name, stat)| {
+            let status = match stat.get_item("status")? {
+                Some(value) if !value.is_none() => value.extract()?,
+                _ => String::new(),
+            };
+            let article_count = match stat.get_item("article_count")? {
+                Some(value) if !value.is_none() => value.extract()?,
+                _ => 0,
+            };
+            Ok((
+                name,
+                PreviousSourceStat {
+                    status,
+                    article_count,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Converts [`EventDetails`] into a Python dictionary with `name`, `start`,
+/// `end`, and `location` keys.
+pub fn event_to_pydict<'py>(py: Python<'py>, event: &EventDetails) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new_bound(py);
+    dict.set_item("name", &event.name)?;
+    dict.set_item("start", &event.start)?;
+    dict.set_item("end", &event.end)?;
+    dict.set_item("location", &event.location)?;
+    Ok(dict)
+}
+
+/// Converts a single [`ParsedArticle`] into a Python dictionary.
+pub fn article_to_pydict<'py>(
+    py: Python<'py>,
+    article: &ParsedArticle,
+) -> PyResult<Bound<'py, PyDict>> {
+    let item = PyDict::new_bound(py);
+    item.set_item("title", &article.title)?;
+    item.set_item("link", &article.link)?;
+    item.set_item("description", &article.description)?;
+    item.set_item("summary", &article.summary)?;
+    item.set_item("likely_truncated", article.likely_truncated)?;
+    item.set_item("published", &article.published)?;
+    item.set_item("published_synthesized", article.published_synthesized)?;
+    item.set_item("source", &article.source)?;
+    item.set_item("authors", &article.authors)?;
+    item.set_item("author_urls", &article.author_urls)?;
+    item.set_item("image", &article.image)?;
+    item.set_item("images", &article.images)?;
+    item.set_item("category", &article.category)?;
+    item.set_item("categories", &article.categories)?;
+    item.set_item("guid", &article.guid)?;
+    match &article.event {
+        Some(event) => item.set_item("event", event_to_pydict(py, event)?)?,
+        None => item.set_item("event", py.None())?,
+    }
+    item.set_item("content_text", &article.content_text)?;
+    item.set_item("category_inferred", article.category_inferred)?;
+    item.set_item("sentiment_score", article.sentiment_score)?;
+    item.set_item("duplicate_guid", article.duplicate_guid)?;
+    item.set_item("sort_key", &article.sort_key)?;
+    item.set_item("content_hash", &article.content_hash)?;
+    item.set_item("word_count", article.word_count)?;
+    item.set_item("reading_time_minutes", article.reading_time_minutes)?;
+    let enclosures = PyList::empty_bound(py);
+    for enclosure in &article.enclosures {
+        let enclosure_dict = PyDict::new_bound(py);
+        enclosure_dict.set_item("url", &enclosure.url)?;
+        enclosure_dict.set_item("mime_type", &enclosure.mime_type)?;
+        enclosure_dict.set_item("length_bytes", enclosure.length_bytes)?;
+        enclosures.append(enclosure_dict)?;
+    }
+    item.set_item("enclosures", enclosures)?;
+    Ok(item)
+}
+
+/// Serializes raw [`FetchResult`]s into a Python dictionary keyed by URL.
+///
+/// Each value is a dict with `status` (the HTTP status code, or `None` when
+/// no response arrived), `body` (the decoded feed text, `None` unless the
+/// fetch succeeded), and `error` (the failure message, else `None`).
+pub fn fetch_results_to_pydict<'py>(
+    py: Python<'py>,
+    results: &[FetchResult],
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new_bound(py);
+    for result in results {
+        let (url, status, body, error) = match result {
+            FetchResult::Success(feed) => (&feed.url, feed.status_code, Some(&feed.xml), None),
+            FetchResult::Error(error) => {
+                (&error.url, error.status_code, None, Some(&error.message))
+            }
+            FetchResult::NotModified(feed) => (&feed.url, feed.status_code, None, None),
+        };
+        let entry = PyDict::new_bound(py);
+        entry.set_item("status", status)?;
+        entry.set_item("body", body)?;
+        entry.set_item("error", error)?;
+        dict.set_item(url, entry)?;
+    }
+    Ok(dict)
+}
+
 /// Serializes an entire [`ParseResult`] into a nested Python dictionary
 /// suitable for returning to Python callers.
 ///
 /// The returned dict contains `articles`, `source_stats`, and `metrics`
-/// keys.
+/// keys, plus `articles_by_source` when grouped output was requested,
+/// `unchanged_sources` when previous stats were supplied, and `raw_xml` when
+/// raw bodies were requested.
 pub fn parse_result_to_pydict<'py>(
     py: Python<'py>,
     result: &ParseResult,
@@ -173,27 +820,56 @@ pub fn parse_result_to_pydict<'py>(
 
     let article_dicts = PyList::empty_bound(py);
     for article in &result.articles {
-        let item = PyDict::new_bound(py);
-        item.set_item("title", &article.title)?;
-        item.set_item("link", &article.link)?;
-        item.set_item("description", &article.description)?;
-        item.set_item("published", &article.published)?;
-        item.set_item("source", &article.source)?;
-        item.set_item("authors", &article.authors)?;
-        item.set_item("author_urls", &article.author_urls)?;
-        item.set_item("image", &article.image)?;
-        item.set_item("category", &article.category)?;
-        article_dicts.append(item)?;
+        article_dicts.append(article_to_pydict(py, article)?)?;
     }
     dict.set_item("articles", article_dicts)?;
 
+    if let Some(grouped) = &result.articles_by_source {
+        let grouped_dict = PyDict::new_bound(py);
+        for (source, articles) in grouped {
+            let source_list = PyList::empty_bound(py);
+            for article in articles {
+                source_list.append(article_to_pydict(py, article)?)?;
+            }
+            grouped_dict.set_item(source, source_list)?;
+        }
+        dict.set_item("articles_by_source", grouped_dict)?;
+    }
+    if let Some(unchanged) = &result.unchanged_sources {
+        dict.set_item("unchanged_sources", unchanged)?;
+    }
+    if let Some(raw_xml) = &result.raw_xml {
+        dict.set_item("raw_xml", raw_xml)?;
+    }
+
     let stats_dict = PyDict::new_bound(py);
     for (name, stat) in &result.source_stats {
         let stat_dict = PyDict::new_bound(py);
         stat_dict.set_item("name", &stat.name)?;
         stat_dict.set_item("status", &stat.status)?;
         stat_dict.set_item("article_count", stat.article_count)?;
+        stat_dict.set_item("returned_count", stat.returned_count)?;
         stat_dict.set_item("error_message", &stat.error_message)?;
+        stat_dict.set_item("error_kinds", &stat.error_kinds)?;
+        stat_dict.set_item("parse_timeout", stat.parse_timeout)?;
+        stat_dict.set_item("blocked_article_count", stat.blocked_article_count)?;
+        stat_dict.set_item("filtered_out_count", stat.filtered_out_count)?;
+        stat_dict.set_item("keyword_filtered_count", stat.keyword_filtered_count)?;
+        stat_dict.set_item("merged_duplicate_count", stat.merged_duplicate_count)?;
+        stat_dict.set_item(
+            "avg_publish_interval_minutes",
+            stat.avg_publish_interval_minutes,
+        )?;
+        stat_dict.set_item("feed_icon_url", &stat.feed_icon_url)?;
+        stat_dict.set_item("feed_icon_data", &stat.feed_icon_data)?;
+        stat_dict.set_item("feed_title", &stat.feed_title)?;
+        stat_dict.set_item("feed_description", &stat.feed_description)?;
+        stat_dict.set_item("last_build_date", &stat.last_build_date)?;
+        stat_dict.set_item("ttl_minutes", stat.ttl_minutes)?;
+        stat_dict.set_item("suggested_poll_after", &stat.suggested_poll_after)?;
+        stat_dict.set_item("dates_synthesized", stat.dates_synthesized)?;
+        stat_dict.set_item("fetch_duration_ms", stat.fetch_duration_ms)?;
+        stat_dict.set_item("parse_duration_ms", stat.parse_duration_ms)?;
 
         if let Some(subs) = &stat.sub_feeds {
             let sub_list = PyList::empty_bound(py);
@@ -203,8 +879,23 @@ pub fn parse_result_to_pydict<'py>(
                 sub_dict.set_item("status", &sub.status)?;
                 sub_dict.set_item("article_count", sub.article_count)?;
                 sub_dict.set_item("error_message", &sub.error_message)?;
+                sub_dict.set_item("error_kind", &sub.error_kind)?;
                 sub_dict.set_item("fetch_duration_ms", sub.fetch_duration_ms)?;
+                sub_dict.set_item("body_bytes", sub.body_bytes)?;
                 sub_dict.set_item("timed_out", sub.timed_out)?;
+                sub_dict.set_item("new_article_count", sub.new_article_count)?;
+                sub_dict.set_item("http_version", &sub.http_version)?;
+                sub_dict.set_item("service_error_suspect", sub.service_error_suspect)?;
+                sub_dict.set_item("duplicate_guid_count", sub.duplicate_guid_count)?;
+                sub_dict.set_item("etag", &sub.etag)?;
+                sub_dict.set_item("last_modified", &sub.last_modified)?;
+                sub_dict.set_item("status_code", sub.status_code)?;
+                sub_dict.set_item("final_url", &sub.final_url)?;
+                sub_dict.set_item("redirects", &sub.redirects)?;
+                sub_dict.set_item("retry_after_secs", sub.retry_after_secs)?;
+                sub_dict.set_item("charset", &sub.charset)?;
+                sub_dict.set_item("content_type", &sub.content_type)?;
+                sub_dict.set_item("suggested_poll_after", &sub.suggested_poll_after)?;
                 sub_list.append(sub_dict)?;
             }
             stat_dict.set_item("sub_feeds", sub_list)?;
@@ -229,7 +920,14 @@ pub fn parse_result_to_pydict<'py>(
         result.metrics.fetch_completed_within_5s,
     )?;
     metrics_dict.set_item("fetch_timed_out", result.metrics.fetch_timed_out)?;
+    metrics_dict.set_item("fetch_errors_by_kind", &result.metrics.fetch_errors_by_kind)?;
+    metrics_dict.set_item(
+        "effective_concurrency",
+        result.metrics.effective_concurrency,
+    )?;
     metrics_dict.set_item("fetch_max_request_ms", result.metrics.fetch_max_request_ms)?;
+    metrics_dict.set_item("articles_blocked", result.metrics.articles_blocked)?;
+    metrics_dict.set_item("cancelled", result.metrics.cancelled)?;
     dict.set_item("metrics", metrics_dict)?;
 
     Ok(dict)